@@ -0,0 +1,38 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { KedolikCpSwap } from "../target/types/kedolik_cp_swap";
+import { addAllowedMint } from "../tests/utils";
+
+// The token-2022 mints of the removed hardcoded whitelist. Pools of these mints
+// can only be created again once they have an allowlist entry.
+export const LEGACY_MINT_WHITELIST = [
+  "HVbpJAQGNpkgBaYBZQBR1t7yFdvaYVp2vCQQfKKEN4tM",
+  "Crn4x1Y2HUKko7ox2EZMT6N2t2ZyH7eKtwkBGVnhEq1g",
+  "FrBfWJ4qE5sCzKm3k3JaAtqZcXUh4LvJygDeketsrsH4",
+  "2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo",
+];
+
+// Create the allowlist entries of the legacy whitelist, entries that exist are skipped.
+// Run with the protocol admin as the provider wallet: `anchor run migrate-mint-allowlist`
+async function main() {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.KedolikCpSwap as Program<KedolikCpSwap>;
+  const owner = (provider.wallet as anchor.Wallet).payer;
+
+  for (const mint of LEGACY_MINT_WHITELIST) {
+    const entry = await addAllowedMint(
+      program,
+      provider.connection,
+      owner,
+      new PublicKey(mint)
+    );
+    console.log("mint:", mint, "allowlist entry:", entry.toString());
+  }
+}
+
+main().catch((err) => {
+  console.error(err);
+  process.exit(1);
+});
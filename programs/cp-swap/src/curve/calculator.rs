@@ -75,6 +75,13 @@ pub struct SwapResult {
     pub creator_fee: u128,
 }
 
+impl SwapResult {
+    /// The price of the input token denominated in the output token after the swap, Q32.32
+    pub fn post_swap_input_price_x32(&self) -> u128 {
+        (self.new_output_vault_amount << 32) / self.new_input_vault_amount
+    }
+}
+
 /// Concrete struct to wrap around the trait object which performs calculation.
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct CurveCalculator {}
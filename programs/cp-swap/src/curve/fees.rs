@@ -41,6 +41,15 @@ impl Fees {
         )
     }
 
+    /// Calculate the referral fee carved out of the protocol fee
+    pub fn referral_fee(protocol_fee: u128, referral_fee_rate: u64) -> Option<u128> {
+        floor_div(
+            protocol_fee,
+            u128::from(referral_fee_rate),
+            u128::from(FEE_RATE_DENOMINATOR_VALUE),
+        )
+    }
+
     /// Calculate the owner fund fee in trading tokens
     pub fn fund_fee(amount: u128, fund_fee_rate: u64) -> Option<u128> {
         floor_div(
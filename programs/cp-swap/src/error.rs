@@ -50,4 +50,52 @@ pub enum ErrorCode {
     FeeDiscountNotEnabled,
     #[msg("Invalid fee token mint")]
     InvalidFeeToken,
+    #[msg("Invalid lp mint metadata")]
+    InvalidLpMintMetadata,
+    #[msg("Mint with a freeze authority is not allowed by the config")]
+    MintFreezeAuthorityNotAllowed,
+    #[msg("Mint with a permanent delegate is not allowed by the config")]
+    MintPermanentDelegateNotAllowed,
+    #[msg("Mint with a mint authority is not allowed by the config")]
+    MintAuthorityNotAllowed,
+    #[msg("Invalid fee rate")]
+    InvalidFeeRate,
+    #[msg("Config change must be queued through the timelock")]
+    TimelockRequired,
+    #[msg("Timelock delay has not passed")]
+    TimelockNotExpired,
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+    #[msg("Pool does not belong to the amm config")]
+    InvalidAmmConfig,
+    #[msg("Permission has expired")]
+    PermissionExpired,
+    #[msg("Permission has reached its max pool count")]
+    PermissionPoolLimitReached,
+    #[msg("Permission is not allowed for the amm config")]
+    PermissionConfigNotAllowed,
+    #[msg("Permission is not allowed to set the creator fee")]
+    PermissionPrivilegeNotAllowed,
+    #[msg("Staked tokens are still locked")]
+    StakeLocked,
+    #[msg("Swap deadline exceeded")]
+    DeadlineExceeded,
+    #[msg("Post swap price is beyond the price limit")]
+    PriceLimitExceeded,
+    #[msg("Invalid return data")]
+    InvalidReturnData,
+    #[msg("The fill price is below the order target price")]
+    LimitOrderNotFillable,
+    #[msg("No amount of the long term order is due")]
+    LongTermOrderNothingDue,
+    #[msg("DCA vault is not due for execution")]
+    DcaVaultNotDue,
+    #[msg("Observations do not cover the twap window")]
+    TwapUnavailable,
+    #[msg("Post swap price deviates from the twap beyond the circuit breaker")]
+    CircuitBreakerTripped,
+    #[msg("The referrer cannot be the payer")]
+    InvalidReferrer,
+    #[msg("The transfer hook program of the mint is not approved")]
+    TransferHookNotApproved,
 }
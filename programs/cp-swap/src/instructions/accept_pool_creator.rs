@@ -0,0 +1,30 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptPoolCreator<'info> {
+    /// The pending pool creator
+    #[account(address = pool_state.load()?.pending_pool_creator @ ErrorCode::InvalidOwner)]
+    pub pending_creator: Signer<'info>,
+
+    /// The pool whose creator is transferred
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+pub fn accept_pool_creator(ctx: Context<AcceptPoolCreator>) -> Result<()> {
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    require_keys_neq!(pool_state.pending_pool_creator, Pubkey::default());
+    let old_creator = pool_state.pool_creator;
+    pool_state.pool_creator = pool_state.pending_pool_creator;
+    pool_state.pending_pool_creator = Pubkey::default();
+
+    emit!(PoolCreatorTransferEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        pool_creator: old_creator,
+        pending_pool_creator: pool_state.pool_creator,
+        accepted: true,
+    });
+    Ok(())
+}
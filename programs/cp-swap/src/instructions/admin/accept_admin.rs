@@ -0,0 +1,33 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    /// The pending admin
+    #[account(address = protocol_state.pending_admin @ ErrorCode::InvalidOwner)]
+    pub pending_admin: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    require_keys_neq!(protocol_state.pending_admin, Pubkey::default());
+    let old_admin = protocol_state.admin;
+    protocol_state.admin = protocol_state.pending_admin;
+    protocol_state.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferEvent {
+        admin: old_admin,
+        pending_admin: protocol_state.admin,
+        accepted: true,
+    });
+    Ok(())
+}
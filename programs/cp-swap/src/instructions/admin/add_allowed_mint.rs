@@ -0,0 +1,54 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use std::ops::DerefMut;
+
+#[derive(Accounts)]
+pub struct AddAllowedMint<'info> {
+    #[account(
+        mut,
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// Mint to be allowed
+    #[account(mint::token_program = token_program_2022)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Allowlist entry of the mint
+    #[account(
+        init,
+        seeds = [
+            MINT_ALLOWLIST_SEED.as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump,
+        payer = owner,
+        space = MintAllowlistEntry::LEN
+    )]
+    pub mint_allowlist_entry: Account<'info, MintAllowlistEntry>,
+
+    /// Spl token program 2022
+    pub token_program_2022: Program<'info, anchor_spl::token_2022::Token2022>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn add_allowed_mint(ctx: Context<AddAllowedMint>) -> Result<()> {
+    let mint_allowlist_entry = ctx.accounts.mint_allowlist_entry.deref_mut();
+    mint_allowlist_entry.mint = ctx.accounts.mint.key();
+
+    emit!(MintAllowlistEvent {
+        mint: mint_allowlist_entry.mint,
+        allowed: true,
+    });
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::swap::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct BuybackProtocolFee<'info> {
+    /// Only admin or protocol_owner can spend the protocol fees
+    #[account(constraint = (owner.key() == amm_config.protocol_owner || owner.key() == protocol_state.admin) @ ErrorCode::InvalidOwner)]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the buyback config
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    /// CHECK: pool vault and lp mint authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Pool state stores accumulated protocol fee amount
+    #[account(
+        mut,
+        constraint = pool_state.key() != protocol_state.buyback_pool @ ErrorCode::InvalidInput
+    )]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// Amm config account stores owner
+    #[account(address = pool_state.load()?.amm_config)]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The pool vault holding the protocol fees to be spent
+    #[account(
+        mut,
+        constraint = fee_vault.key() == pool_state.load()?.token_0_vault || fee_vault.key() == pool_state.load()?.token_1_vault
+    )]
+    pub fee_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of the protocol fees
+    #[account(address = fee_vault.mint)]
+    pub fee_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The pool the protocol fees are swapped through
+    #[account(mut, address = protocol_state.buyback_pool)]
+    pub buyback_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The amm config of the buyback pool
+    #[account(address = buyback_pool_state.load()?.amm_config)]
+    pub buyback_amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The buyback pool vault receiving the protocol fees
+    #[account(
+        mut,
+        constraint = buyback_input_vault.key() == buyback_pool_state.load()?.token_0_vault || buyback_input_vault.key() == buyback_pool_state.load()?.token_1_vault,
+        constraint = buyback_input_vault.mint == fee_mint.key() @ ErrorCode::InvalidInput
+    )]
+    pub buyback_input_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The buyback pool vault holding the K token
+    #[account(
+        mut,
+        constraint = buyback_output_vault.key() == buyback_pool_state.load()?.token_0_vault || buyback_output_vault.key() == buyback_pool_state.load()?.token_1_vault,
+        constraint = buyback_output_vault.mint == protocol_state.buyback_mint @ ErrorCode::InvalidInput
+    )]
+    pub buyback_output_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The K token mint
+    #[account(mut, address = protocol_state.buyback_mint)]
+    pub buyback_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The oracle observation of the buyback pool
+    #[account(mut, address = buyback_pool_state.load()?.observation_key)]
+    pub buyback_observation_state: AccountLoader<'info, ObservationState>,
+
+    /// The token account receiving the K token, required when the buyback destination is set
+    #[account(mut)]
+    pub buyback_destination: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// SPL program for the protocol fee token transfers
+    pub fee_token_program: Interface<'info, TokenInterface>,
+
+    /// SPL program for the K token burns and transfers
+    pub buyback_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Swap the protocol fees of a pool through the buyback pool and burn the K token received,
+/// or send it to the buyback destination when one is set
+pub fn buyback_protocol_fee<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuybackProtocolFee<'info>>,
+    amount_requested: u64,
+    minimum_amount_out: u64,
+) -> Result<()> {
+    let auth_signer_seeds: &[&[u8]] = &[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]];
+
+    // take the protocol fees from the pool
+    let amount_in = {
+        let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+        let amount_in = if ctx.accounts.fee_vault.key() == pool_state.token_0_vault {
+            let amount_in = amount_requested.min(pool_state.protocol_fees_token_0);
+            pool_state.protocol_fees_token_0 -= amount_in;
+            amount_in
+        } else {
+            let amount_in = amount_requested.min(pool_state.protocol_fees_token_1);
+            pool_state.protocol_fees_token_1 -= amount_in;
+            amount_in
+        };
+        pool_state.recent_epoch = Clock::get()?.epoch;
+        amount_in
+    };
+    if amount_in == 0 {
+        return err!(ErrorCode::NoFeeCollect);
+    }
+
+    let buyback_pool_id = ctx.accounts.buyback_pool_state.key();
+    let buyback_pool_state = &mut ctx.accounts.buyback_pool_state.load_mut()?;
+    let swap_result = swap_base_input_in_pool(
+        buyback_pool_state,
+        &PoolSwapAccounts {
+            pool_id: buyback_pool_id,
+            amm_config: &ctx.accounts.buyback_amm_config,
+            protocol_state: &ctx.accounts.protocol_state,
+            input_vault: &ctx.accounts.buyback_input_vault,
+            output_vault: &ctx.accounts.buyback_output_vault,
+            input_token_mint: &ctx.accounts.fee_mint,
+            output_token_mint: &ctx.accounts.buyback_mint,
+            observation_state: &ctx.accounts.buyback_observation_state,
+        },
+        amount_in,
+    )?;
+    let amount_out = swap_result.amount_out;
+    require_gte!(amount_out, minimum_amount_out, ErrorCode::ExceededSlippage);
+
+    // move the protocol fees into the buyback pool
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.fee_vault.to_account_info(),
+        ctx.accounts.buyback_input_vault.to_account_info(),
+        ctx.accounts.fee_mint.to_account_info(),
+        ctx.accounts.fee_token_program.to_account_info(),
+        amount_in,
+        ctx.accounts.fee_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    let buyback_destination = ctx.accounts.protocol_state.buyback_destination;
+    let burned = buyback_destination == Pubkey::default();
+    if burned {
+        token_burn(
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.buyback_token_program.to_account_info(),
+            ctx.accounts.buyback_mint.to_account_info(),
+            ctx.accounts.buyback_output_vault.to_account_info(),
+            amount_out,
+            &[auth_signer_seeds],
+        )?;
+    } else {
+        let destination = ctx
+            .accounts
+            .buyback_destination
+            .as_ref()
+            .ok_or(ErrorCode::InvalidInput)?;
+        require_keys_eq!(
+            destination.key(),
+            buyback_destination,
+            ErrorCode::InvalidInput
+        );
+        transfer_from_pool_vault_to_user(
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.buyback_output_vault.to_account_info(),
+            destination.to_account_info(),
+            ctx.accounts.buyback_mint.to_account_info(),
+            ctx.accounts.buyback_token_program.to_account_info(),
+            amount_out,
+            ctx.accounts.buyback_mint.decimals,
+            &[auth_signer_seeds],
+            ctx.remaining_accounts,
+        )?;
+    }
+
+    emit!(BuybackEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        buyback_pool_id,
+        fee_mint: ctx.accounts.fee_mint.key(),
+        amount_in,
+        input_transfer_fee: swap_result.input_transfer_fee,
+        buyback_amount: amount_out,
+        burned,
+    });
+    Ok(())
+}
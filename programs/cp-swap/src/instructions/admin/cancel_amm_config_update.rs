@@ -0,0 +1,42 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelAmmConfigUpdate<'info> {
+    #[account(mut, address = protocol_state.admin @ ErrorCode::InvalidOwner)]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: amm config of the queued update
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// The queued update of the amm config
+    #[account(
+        mut,
+        seeds = [
+            QUEUED_CONFIG_UPDATE_SEED.as_bytes(),
+            amm_config.key().as_ref()
+        ],
+        bump,
+        close = owner
+    )]
+    pub queued_config_update: Account<'info, QueuedConfigUpdate>,
+}
+
+pub fn cancel_amm_config_update(ctx: Context<CancelAmmConfigUpdate>) -> Result<()> {
+    let queued_config_update = &ctx.accounts.queued_config_update;
+    emit!(QueuedConfigUpdateEvent {
+        amm_config: queued_config_update.amm_config,
+        params: queued_config_update.params.clone(),
+        executable_at: queued_config_update.executable_at,
+        change_type: 2,
+    });
+    Ok(())
+}
@@ -6,10 +6,17 @@ use anchor_lang::prelude::*;
 pub struct ClosePermissionPda<'info> {
     #[account(
         mut,
-        address = crate::admin::ID @ ErrorCode::InvalidOwner
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
     )]
     pub owner: Signer<'info>,
 
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
     /// CHECK: permission account authority
     pub permission_authority: UncheckedAccount<'info>,
 
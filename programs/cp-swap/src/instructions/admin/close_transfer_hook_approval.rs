@@ -0,0 +1,40 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseTransferHookApproval<'info> {
+    #[account(
+        mut,
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: approved transfer hook program
+    pub hook_program: UncheckedAccount<'info>,
+
+    /// Approval account of the transfer hook program
+    #[account(
+        mut,
+        seeds = [
+            TRANSFER_HOOK_APPROVAL_SEED.as_bytes(),
+            hook_program.key().as_ref()
+        ],
+        bump,
+        close = owner
+    )]
+    pub transfer_hook_approval: Account<'info, TransferHookApproval>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_transfer_hook_approval(_ctx: Context<CloseTransferHookApproval>) -> Result<()> {
+    Ok(())
+}
@@ -9,10 +9,17 @@ pub struct CreateAmmConfig<'info> {
     /// Address to be set as protocol owner.
     #[account(
         mut,
-        address = crate::admin::ID @ ErrorCode::InvalidOwner
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
     )]
     pub owner: Signer<'info>,
 
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
     /// Initialize config state account to store protocol owner address and fee rates.
     #[account(
         init,
@@ -53,5 +60,5 @@ pub fn create_amm_config(
     amm_config.creator_fee_rate = creator_fee_rate;
     amm_config.fee_token_mint = fee_token_mint;
     amm_config.fee_token_discount_rate = fee_token_discount_rate;
-    Ok(())
+    amm_config.validate()
 }
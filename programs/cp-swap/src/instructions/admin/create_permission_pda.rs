@@ -7,10 +7,17 @@ use std::ops::DerefMut;
 pub struct CreatePermissionPda<'info> {
     #[account(
         mut,
-        address = crate::admin::ID @ ErrorCode::InvalidOwner
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
     )]
     pub owner: Signer<'info>,
 
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
     /// CHECK: permission account authority
     pub permission_authority: UncheckedAccount<'info>,
 
@@ -30,8 +37,11 @@ pub struct CreatePermissionPda<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn create_permission_pda(ctx: Context<CreatePermissionPda>) -> Result<()> {
+pub fn create_permission_pda(
+    ctx: Context<CreatePermissionPda>,
+    scope: PermissionScope,
+) -> Result<()> {
     let permission = ctx.accounts.permission.deref_mut();
     permission.authority = ctx.accounts.permission_authority.key();
-    Ok(())
+    permission.set_scope(&scope)
 }
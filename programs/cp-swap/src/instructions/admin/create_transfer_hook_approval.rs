@@ -0,0 +1,45 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use std::ops::DerefMut;
+
+#[derive(Accounts)]
+pub struct CreateTransferHookApproval<'info> {
+    #[account(
+        mut,
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: transfer hook program to be approved
+    #[account(executable)]
+    pub hook_program: UncheckedAccount<'info>,
+
+    /// Approval account of the transfer hook program
+    #[account(
+        init,
+        seeds = [
+            TRANSFER_HOOK_APPROVAL_SEED.as_bytes(),
+            hook_program.key().as_ref()
+        ],
+        bump,
+        payer = owner,
+        space = TransferHookApproval::LEN
+    )]
+    pub transfer_hook_approval: Account<'info, TransferHookApproval>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_transfer_hook_approval(ctx: Context<CreateTransferHookApproval>) -> Result<()> {
+    let transfer_hook_approval = ctx.accounts.transfer_hook_approval.deref_mut();
+    transfer_hook_approval.program_id = ctx.accounts.hook_program.key();
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteAmmConfigUpdate<'info> {
+    #[account(mut, address = protocol_state.admin @ ErrorCode::InvalidOwner)]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// Amm config account to be changed
+    #[account(mut)]
+    pub amm_config: Account<'info, AmmConfig>,
+
+    /// The queued update of the amm config
+    #[account(
+        mut,
+        seeds = [
+            QUEUED_CONFIG_UPDATE_SEED.as_bytes(),
+            amm_config.key().as_ref()
+        ],
+        bump,
+        close = owner
+    )]
+    pub queued_config_update: Account<'info, QueuedConfigUpdate>,
+}
+
+pub fn execute_amm_config_update(ctx: Context<ExecuteAmmConfigUpdate>) -> Result<()> {
+    let queued_config_update = &ctx.accounts.queued_config_update;
+    require_gte!(
+        Clock::get()?.unix_timestamp as u64,
+        queued_config_update.executable_at,
+        ErrorCode::TimelockNotExpired
+    );
+
+    let amm_config = &mut ctx.accounts.amm_config;
+    let old = amm_config.snapshot();
+    amm_config.update(&queued_config_update.params)?;
+
+    emit!(ConfigUpdatedEvent {
+        amm_config: amm_config.key(),
+        old,
+        new: amm_config.snapshot(),
+    });
+    emit!(QueuedConfigUpdateEvent {
+        amm_config: amm_config.key(),
+        params: queued_config_update.params.clone(),
+        executable_at: queued_config_update.executable_at,
+        change_type: 1,
+    });
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use std::ops::DerefMut;
+
+#[derive(Accounts)]
+pub struct InitializeProtocolState<'info> {
+    /// The deploy time admin, becomes the admin of the protocol state
+    #[account(
+        mut,
+        address = crate::admin::ID @ ErrorCode::InvalidOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Initialize protocol state account to store the admin
+    #[account(
+        init,
+        seeds = [
+            PROTOCOL_STATE_SEED.as_bytes()
+        ],
+        bump,
+        payer = owner,
+        space = ProtocolState::LEN
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_protocol_state(ctx: Context<InitializeProtocolState>) -> Result<()> {
+    let protocol_state = ctx.accounts.protocol_state.deref_mut();
+    protocol_state.bump = ctx.bumps.protocol_state;
+    protocol_state.admin = ctx.accounts.owner.key();
+    protocol_state.pending_admin = Pubkey::default();
+    Ok(())
+}
@@ -7,6 +7,9 @@ pub use update_config::*;
 pub mod update_pool_status;
 pub use update_pool_status::*;
 
+pub mod update_pool_ui_amount_pricing;
+pub use update_pool_ui_amount_pricing::*;
+
 pub mod collect_protocol_fee;
 pub use collect_protocol_fee::*;
 
@@ -16,5 +19,68 @@ pub use collect_fund_fee::*;
 pub mod create_permission_pda;
 pub use create_permission_pda::*;
 
+pub mod update_permission_pda;
+pub use update_permission_pda::*;
+
 pub mod close_permission_pda;
 pub use close_permission_pda::*;
+
+pub mod create_transfer_hook_approval;
+pub use create_transfer_hook_approval::*;
+
+pub mod close_transfer_hook_approval;
+pub use close_transfer_hook_approval::*;
+
+pub mod add_allowed_mint;
+pub use add_allowed_mint::*;
+
+pub mod remove_allowed_mint;
+pub use remove_allowed_mint::*;
+
+pub mod initialize_protocol_state;
+pub use initialize_protocol_state::*;
+
+pub mod transfer_admin;
+pub use transfer_admin::*;
+
+pub mod accept_admin;
+pub use accept_admin::*;
+
+pub mod update_timelock_delay;
+pub use update_timelock_delay::*;
+
+pub mod queue_amm_config_update;
+pub use queue_amm_config_update::*;
+
+pub mod execute_amm_config_update;
+pub use execute_amm_config_update::*;
+
+pub mod cancel_amm_config_update;
+pub use cancel_amm_config_update::*;
+
+pub mod set_guardian;
+pub use set_guardian::*;
+
+pub mod pause_pools;
+pub use pause_pools::*;
+
+pub mod update_protocol_status;
+pub use update_protocol_status::*;
+
+pub mod set_fee_distribution;
+pub use set_fee_distribution::*;
+
+pub mod set_buyback_config;
+pub use set_buyback_config::*;
+
+pub mod buyback_protocol_fee;
+pub use buyback_protocol_fee::*;
+
+pub mod update_stake_tiers;
+pub use update_stake_tiers::*;
+
+pub mod set_circuit_breaker;
+pub use set_circuit_breaker::*;
+
+pub mod reset_circuit_breaker;
+pub use reset_circuit_breaker::*;
@@ -0,0 +1,54 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use std::ops::BitOr;
+
+#[derive(Accounts)]
+pub struct PausePools<'info> {
+    /// The guardian or admin
+    #[account(
+        constraint = (authority.key() == protocol_state.guardian || authority.key() == protocol_state.admin) @ ErrorCode::InvalidOwner
+    )]
+    pub authority: Signer<'info>,
+
+    /// Protocol state holding the admin and guardian
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// The amm config of the pools
+    pub amm_config: Account<'info, AmmConfig>,
+}
+
+/// Disable the `status` bits of the pools passed in the remaining accounts.
+/// The bits can only be set here, re-enabling a pool requires the admin through `update_pool_status`.
+pub fn pause_pools<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PausePools<'info>>,
+    status: u8,
+) -> Result<()> {
+    require!(status != 0 && status <= 7, ErrorCode::InvalidInput);
+    require!(!ctx.remaining_accounts.is_empty(), ErrorCode::InvalidInput);
+
+    let recent_epoch = Clock::get()?.epoch;
+    for pool_account_info in ctx.remaining_accounts.iter() {
+        let pool_loader = AccountLoader::<PoolState>::try_from(pool_account_info)?;
+        let mut pool_state = pool_loader.load_mut()?;
+        require_keys_eq!(
+            pool_state.amm_config,
+            ctx.accounts.amm_config.key(),
+            ErrorCode::InvalidAmmConfig
+        );
+        let new_status = pool_state.status.bitor(status);
+        pool_state.set_status(new_status);
+        pool_state.recent_epoch = recent_epoch;
+
+        emit!(PoolPausedEvent {
+            pool_id: pool_account_info.key(),
+            disabled_status: status,
+            status: new_status,
+        });
+    }
+    Ok(())
+}
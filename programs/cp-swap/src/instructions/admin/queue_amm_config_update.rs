@@ -0,0 +1,62 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use std::ops::DerefMut;
+
+#[derive(Accounts)]
+pub struct QueueAmmConfigUpdate<'info> {
+    #[account(mut, address = protocol_state.admin @ ErrorCode::InvalidOwner)]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// Amm config account to be changed
+    pub amm_config: Account<'info, AmmConfig>,
+
+    /// The queued update of the amm config
+    #[account(
+        init,
+        seeds = [
+            QUEUED_CONFIG_UPDATE_SEED.as_bytes(),
+            amm_config.key().as_ref()
+        ],
+        bump,
+        payer = owner,
+        space = QueuedConfigUpdate::LEN
+    )]
+    pub queued_config_update: Account<'info, QueuedConfigUpdate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn queue_amm_config_update(
+    ctx: Context<QueueAmmConfigUpdate>,
+    params: AmmConfigUpdate,
+) -> Result<()> {
+    // Reject invalid updates now rather than after the delay
+    let mut amm_config = ctx.accounts.amm_config.clone().into_inner();
+    amm_config.update(&params)?;
+
+    let queued_at = Clock::get()?.unix_timestamp as u64;
+    let executable_at = queued_at
+        .checked_add(ctx.accounts.protocol_state.timelock_delay)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let queued_config_update = ctx.accounts.queued_config_update.deref_mut();
+    queued_config_update.amm_config = ctx.accounts.amm_config.key();
+    queued_config_update.params = params.clone();
+    queued_config_update.queued_at = queued_at;
+    queued_config_update.executable_at = executable_at;
+
+    emit!(QueuedConfigUpdateEvent {
+        amm_config: queued_config_update.amm_config,
+        params,
+        executable_at,
+        change_type: 0,
+    });
+    Ok(())
+}
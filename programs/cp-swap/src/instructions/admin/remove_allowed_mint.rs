@@ -0,0 +1,44 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RemoveAllowedMint<'info> {
+    #[account(
+        mut,
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: allowed mint
+    pub mint: UncheckedAccount<'info>,
+
+    /// Allowlist entry of the mint
+    #[account(
+        mut,
+        seeds = [
+            MINT_ALLOWLIST_SEED.as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump,
+        close = owner
+    )]
+    pub mint_allowlist_entry: Account<'info, MintAllowlistEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>) -> Result<()> {
+    emit!(MintAllowlistEvent {
+        mint: ctx.accounts.mint.key(),
+        allowed: false,
+    });
+    Ok(())
+}
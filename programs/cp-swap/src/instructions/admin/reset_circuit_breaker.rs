@@ -0,0 +1,38 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ResetCircuitBreaker<'info> {
+    #[account(
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+/// Resume swaps of a pool paused by its circuit breaker
+pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    require!(pool_state.circuit_breaker_tripped, ErrorCode::InvalidInput);
+    pool_state.circuit_breaker_tripped = false;
+    pool_state.set_status_by_bit(PoolStatusBitIndex::Swap, PoolStatusBitFlag::Enable);
+    pool_state.recent_epoch = Clock::get()?.epoch;
+
+    emit!(CircuitBreakerEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        token_0_price_x32: 0,
+        twap_token_0_price_x32: 0,
+        change_type: 1,
+    });
+    Ok(())
+}
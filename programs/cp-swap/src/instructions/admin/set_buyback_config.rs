@@ -0,0 +1,50 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+#[derive(Accounts)]
+pub struct SetBuybackConfig<'info> {
+    #[account(address = protocol_state.admin @ ErrorCode::InvalidOwner)]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the buyback config
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// The pool protocol fees are swapped through
+    pub buyback_pool_state: AccountLoader<'info, PoolState>,
+
+    /// The K token mint, must be one of the buyback pool mints
+    #[account(
+        constraint = buyback_mint.key() == buyback_pool_state.load()?.token_0_mint || buyback_mint.key() == buyback_pool_state.load()?.token_1_mint @ ErrorCode::InvalidInput
+    )]
+    pub buyback_mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+pub fn set_buyback_config(
+    ctx: Context<SetBuybackConfig>,
+    buyback_destination: Pubkey,
+) -> Result<()> {
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let old_buyback_pool = protocol_state.buyback_pool;
+    let old_buyback_mint = protocol_state.buyback_mint;
+    let old_buyback_destination = protocol_state.buyback_destination;
+    protocol_state.buyback_pool = ctx.accounts.buyback_pool_state.key();
+    protocol_state.buyback_mint = ctx.accounts.buyback_mint.key();
+    protocol_state.buyback_destination = buyback_destination;
+
+    emit!(BuybackConfigUpdatedEvent {
+        old_buyback_pool,
+        new_buyback_pool: protocol_state.buyback_pool,
+        old_buyback_mint,
+        new_buyback_mint: protocol_state.buyback_mint,
+        old_buyback_destination,
+        new_buyback_destination: buyback_destination,
+    });
+    Ok(())
+}
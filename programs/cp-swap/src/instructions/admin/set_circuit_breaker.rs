@@ -0,0 +1,51 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetCircuitBreaker<'info> {
+    #[account(
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+pub fn set_circuit_breaker(
+    ctx: Context<SetCircuitBreaker>,
+    max_deviation_bps: u16,
+    window: u64,
+    pause: bool,
+) -> Result<()> {
+    require_gte!(
+        CIRCUIT_BREAKER_BPS_DENOMINATOR,
+        u64::from(max_deviation_bps),
+        ErrorCode::InvalidInput
+    );
+    require!(
+        max_deviation_bps == 0 || (window > 0 && window <= MAX_CIRCUIT_BREAKER_WINDOW),
+        ErrorCode::InvalidInput
+    );
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.circuit_breaker_bps = max_deviation_bps;
+    pool_state.circuit_breaker_window = window;
+    pool_state.circuit_breaker_pause = pause;
+    pool_state.recent_epoch = Clock::get()?.epoch;
+
+    emit!(CircuitBreakerUpdatedEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        max_deviation_bps,
+        window,
+        pause,
+    });
+    Ok(())
+}
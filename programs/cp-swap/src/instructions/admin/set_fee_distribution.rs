@@ -0,0 +1,59 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(fee_kind: u8)]
+pub struct SetFeeDistribution<'info> {
+    /// The protocol_owner / fund_owner of the distributed fees. The admin can only replace
+    /// them through the timelocked amm config update, so it cannot set the distribution
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The amm config whose pools' fees are distributed
+    pub amm_config: Account<'info, AmmConfig>,
+
+    /// The fee distribution of the amm config
+    #[account(
+        init_if_needed,
+        seeds = [
+            FEE_DISTRIBUTION_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+            &[fee_kind],
+        ],
+        bump,
+        payer = owner,
+        space = FeeDistribution::LEN
+    )]
+    pub fee_distribution: Account<'info, FeeDistribution>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_fee_distribution(
+    ctx: Context<SetFeeDistribution>,
+    fee_kind: u8,
+    recipients: Vec<FeeRecipient>,
+) -> Result<()> {
+    let fee_owner = if fee_kind == FeeDistributionKind::Protocol as u8 {
+        ctx.accounts.amm_config.protocol_owner
+    } else if fee_kind == FeeDistributionKind::Fund as u8 {
+        ctx.accounts.amm_config.fund_owner
+    } else {
+        return err!(ErrorCode::InvalidInput);
+    };
+    require_keys_eq!(ctx.accounts.owner.key(), fee_owner, ErrorCode::InvalidOwner);
+
+    let fee_distribution = &mut ctx.accounts.fee_distribution;
+    fee_distribution.bump = ctx.bumps.fee_distribution;
+    fee_distribution.fee_kind = fee_kind;
+    fee_distribution.amm_config = ctx.accounts.amm_config.key();
+    fee_distribution.set_recipients(&recipients)?;
+
+    emit!(FeeDistributionUpdatedEvent {
+        amm_config: fee_distribution.amm_config,
+        fee_kind,
+        recipients,
+    });
+    Ok(())
+}
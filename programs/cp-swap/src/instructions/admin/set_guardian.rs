@@ -0,0 +1,29 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(address = protocol_state.admin @ ErrorCode::InvalidOwner)]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let old_guardian = protocol_state.guardian;
+    protocol_state.guardian = guardian;
+
+    emit!(GuardianUpdatedEvent {
+        old_guardian,
+        new_guardian: guardian,
+    });
+    Ok(())
+}
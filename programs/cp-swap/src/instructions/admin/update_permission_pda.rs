@@ -0,0 +1,39 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdatePermissionPda<'info> {
+    #[account(
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
+    )]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// CHECK: permission account authority
+    pub permission_authority: UncheckedAccount<'info>,
+
+    /// The permission account to be updated
+    #[account(
+        mut,
+        seeds = [
+            PERMISSION_SEED.as_bytes(),
+            permission_authority.key().as_ref()
+        ],
+        bump,
+    )]
+    pub permission: Account<'info, Permission>,
+}
+
+pub fn update_permission_pda(
+    ctx: Context<UpdatePermissionPda>,
+    scope: PermissionScope,
+) -> Result<()> {
+    ctx.accounts.permission.set_scope(&scope)
+}
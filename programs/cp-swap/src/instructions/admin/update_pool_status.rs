@@ -1,13 +1,21 @@
+use crate::error::ErrorCode;
 use crate::states::*;
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct UpdatePoolStatus<'info> {
     #[account(
-        address = crate::admin::ID
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
     )]
     pub authority: Signer<'info>,
 
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
     #[account(mut)]
     pub pool_state: AccountLoader<'info, PoolState>,
 }
@@ -0,0 +1,46 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdatePoolUiAmountPricing<'info> {
+    #[account(
+        address = protocol_state.admin @ ErrorCode::InvalidOwner
+    )]
+    pub authority: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The oracle observations of the pool, reset as their price space changes
+    #[account(mut, address = pool_state.load()?.observation_key)]
+    pub observation_state: AccountLoader<'info, ObservationState>,
+}
+
+pub fn update_pool_ui_amount_pricing(
+    ctx: Context<UpdatePoolUiAmountPricing>,
+    ui_amount_pricing: bool,
+) -> Result<()> {
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    require!(
+        pool_state.ui_amount_pricing != ui_amount_pricing,
+        ErrorCode::InvalidInput
+    );
+    pool_state.ui_amount_pricing = ui_amount_pricing;
+    pool_state.recent_epoch = Clock::get()?.epoch;
+    // raw and ui amount prices must not be mixed in the cumulative prices of the twap
+    ctx.accounts.observation_state.load_mut()?.reset();
+
+    emit!(PoolUiAmountPricingEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        ui_amount_pricing,
+    });
+    Ok(())
+}
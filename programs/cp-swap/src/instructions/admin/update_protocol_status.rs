@@ -0,0 +1,42 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use std::ops::BitAnd;
+
+#[derive(Accounts)]
+pub struct UpdateProtocolStatus<'info> {
+    /// The admin, or the guardian which can only disable
+    #[account(
+        constraint = (authority.key() == protocol_state.guardian || authority.key() == protocol_state.admin) @ ErrorCode::InvalidOwner
+    )]
+    pub authority: Signer<'info>,
+
+    /// Protocol state holding the protocol-wide status
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+pub fn update_protocol_status(ctx: Context<UpdateProtocolStatus>, status: u8) -> Result<()> {
+    require_gte!(7, status, ErrorCode::InvalidInput);
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    let old_status = protocol_state.status;
+    if ctx.accounts.authority.key() != protocol_state.admin {
+        // The guardian can not re-enable
+        require_eq!(
+            status.bitand(old_status),
+            old_status,
+            ErrorCode::InvalidOwner
+        );
+    }
+    protocol_state.status = status;
+
+    emit!(ProtocolStatusUpdatedEvent {
+        old_status,
+        new_status: status,
+    });
+    Ok(())
+}
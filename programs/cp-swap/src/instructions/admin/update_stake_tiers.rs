@@ -0,0 +1,47 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateStakeTiers<'info> {
+    /// The amm config admin
+    #[account(address = protocol_state.admin @ ErrorCode::InvalidOwner)]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// Amm config account to be changed
+    #[account(mut)]
+    pub amm_config: Account<'info, AmmConfig>,
+}
+
+/// Staking tiers change the trade fee, so when the timelock is enabled they are queued
+/// through `queue_amm_config_update` like the other fee changes
+pub fn update_stake_tiers(
+    ctx: Context<UpdateStakeTiers>,
+    stake_tier_thresholds: [u64; STAKE_TIER_NUM],
+    stake_tier_discount_rates: [u64; STAKE_TIER_NUM],
+) -> Result<()> {
+    require!(
+        ctx.accounts.protocol_state.timelock_delay == 0,
+        ErrorCode::TimelockRequired
+    );
+    let amm_config = &mut ctx.accounts.amm_config;
+    amm_config.update(&AmmConfigUpdate {
+        stake_tier_thresholds: Some(stake_tier_thresholds),
+        stake_tier_discount_rates: Some(stake_tier_discount_rates),
+        ..Default::default()
+    })?;
+
+    emit!(StakeTiersUpdatedEvent {
+        amm_config: amm_config.key(),
+        stake_tier_thresholds,
+        stake_tier_discount_rates,
+    });
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateTimelockDelay<'info> {
+    #[account(address = protocol_state.admin @ ErrorCode::InvalidOwner)]
+    pub owner: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        mut,
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+}
+
+/// The delay can only be increased, otherwise the admin could skip the timelock
+pub fn update_timelock_delay(ctx: Context<UpdateTimelockDelay>, timelock_delay: u64) -> Result<()> {
+    let protocol_state = &mut ctx.accounts.protocol_state;
+    require!(
+        timelock_delay >= protocol_state.timelock_delay && timelock_delay <= MAX_TIMELOCK_DELAY,
+        ErrorCode::InvalidTimelockDelay
+    );
+    let old_delay = protocol_state.timelock_delay;
+    protocol_state.timelock_delay = timelock_delay;
+
+    emit!(TimelockDelayUpdatedEvent {
+        old_delay,
+        new_delay: timelock_delay,
+    });
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CancelLimitOrder<'info> {
+    /// The owner of the order, receives the rent and the keeper bounty back
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: pool vault and order vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The order
+    #[account(
+        mut,
+        has_one = owner,
+        close = owner,
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    /// The mint of the escrowed token
+    #[account(address = limit_order.input_mint)]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The vault escrowing the input tokens of all orders
+    #[account(
+        mut,
+        seeds = [
+            LIMIT_ORDER_VAULT_SEED.as_bytes(),
+            input_token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account receiving the escrowed tokens
+    #[account(
+        mut,
+        token::mint = input_token_mint,
+    )]
+    pub owner_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022 of the input token
+    pub input_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn cancel_limit_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, CancelLimitOrder<'info>>,
+) -> Result<()> {
+    let limit_order = &ctx.accounts.limit_order;
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.order_vault.to_account_info(),
+        ctx.accounts.owner_input_token_account.to_account_info(),
+        ctx.accounts.input_token_mint.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        limit_order.amount_in,
+        ctx.accounts.input_token_mint.decimals,
+        &[&[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]]],
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(LimitOrderEvent {
+        order_id: limit_order.key(),
+        owner: limit_order.owner,
+        pool_id: limit_order.pool_id,
+        input_mint: limit_order.input_mint,
+        output_mint: limit_order.output_mint,
+        amount_in: limit_order.amount_in,
+        amount_out: 0,
+        target_price_x32: limit_order.target_price_x32,
+        keeper_bounty: limit_order.keeper_bounty,
+        change_type: 1,
+    });
+    Ok(())
+}
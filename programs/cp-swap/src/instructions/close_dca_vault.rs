@@ -0,0 +1,121 @@
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CloseDcaVault<'info> {
+    /// The owner of the DCA vault, receives the rent back
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: pool vault and DCA token vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The DCA vault
+    #[account(
+        mut,
+        has_one = owner,
+        close = owner,
+    )]
+    pub dca_vault: Box<Account<'info, DcaVault>>,
+
+    /// The mint of the token sold
+    #[account(address = dca_vault.input_mint)]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of the token bought
+    #[account(address = dca_vault.output_mint)]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token vault holding the input tokens of all DCA vaults
+    #[account(
+        mut,
+        seeds = [
+            DCA_TOKEN_VAULT_SEED.as_bytes(),
+            input_token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub input_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token vault holding the bought tokens of all DCA vaults
+    #[account(
+        mut,
+        seeds = [
+            DCA_TOKEN_VAULT_SEED.as_bytes(),
+            output_token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub output_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account receiving the unsold input tokens
+    #[account(
+        mut,
+        token::mint = input_token_mint,
+    )]
+    pub owner_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account receiving the bought tokens
+    #[account(
+        mut,
+        token::mint = output_token_mint,
+    )]
+    pub owner_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022 of the input token
+    pub input_token_program: Interface<'info, TokenInterface>,
+    /// Spl token program or token program 2022 of the output token
+    pub output_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Close a DCA vault, returning the unsold input tokens and the bought tokens to the owner
+pub fn close_dca_vault<'info>(ctx: Context<'_, '_, '_, 'info, CloseDcaVault<'info>>) -> Result<()> {
+    let dca_vault = &ctx.accounts.dca_vault;
+    let auth_signer_seeds: &[&[u8]] = &[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]];
+
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.input_token_vault.to_account_info(),
+        ctx.accounts.owner_input_token_account.to_account_info(),
+        ctx.accounts.input_token_mint.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        dca_vault.input_amount,
+        ctx.accounts.input_token_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.output_token_vault.to_account_info(),
+        ctx.accounts.owner_output_token_account.to_account_info(),
+        ctx.accounts.output_token_mint.to_account_info(),
+        ctx.accounts.output_token_program.to_account_info(),
+        dca_vault.output_amount,
+        ctx.accounts.output_token_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(DcaVaultEvent {
+        dca_vault: dca_vault.key(),
+        owner: dca_vault.owner,
+        pool_id: dca_vault.pool_id,
+        input_mint: dca_vault.input_mint,
+        output_mint: dca_vault.output_mint,
+        amount_in: dca_vault.input_amount,
+        amount_out: dca_vault.output_amount,
+        input_amount: 0,
+        output_amount: 0,
+        change_type: 2,
+    });
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct CloseLongTermOrder<'info> {
+    /// The owner of the order, receives the rent back
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: pool vault and order vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The order
+    #[account(
+        mut,
+        has_one = owner,
+        close = owner,
+    )]
+    pub long_term_order: Box<Account<'info, LongTermOrder>>,
+
+    /// The mint of the escrowed token
+    #[account(address = long_term_order.input_mint)]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of the token bought
+    #[account(address = long_term_order.output_mint)]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The vault escrowing the input tokens of all orders
+    #[account(
+        mut,
+        seeds = [
+            LONG_TERM_ORDER_VAULT_SEED.as_bytes(),
+            input_token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub input_order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault holding the bought tokens of all orders
+    #[account(
+        mut,
+        seeds = [
+            LONG_TERM_ORDER_VAULT_SEED.as_bytes(),
+            output_token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub output_order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account receiving the unsold input tokens
+    #[account(
+        mut,
+        token::mint = input_token_mint,
+    )]
+    pub owner_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account receiving the bought tokens
+    #[account(
+        mut,
+        token::mint = output_token_mint,
+    )]
+    pub owner_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022 of the input token
+    pub input_token_program: Interface<'info, TokenInterface>,
+    /// Spl token program or token program 2022 of the output token
+    pub output_token_program: Interface<'info, TokenInterface>,
+}
+
+/// Close a long term order, returning the unsold input tokens and the bought tokens to the owner
+pub fn close_long_term_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, CloseLongTermOrder<'info>>,
+) -> Result<()> {
+    let long_term_order = &ctx.accounts.long_term_order;
+    let auth_signer_seeds: &[&[u8]] = &[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]];
+    let unsold_amount = long_term_order.deposit_amount - long_term_order.sold_amount;
+
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.input_order_vault.to_account_info(),
+        ctx.accounts.owner_input_token_account.to_account_info(),
+        ctx.accounts.input_token_mint.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        unsold_amount,
+        ctx.accounts.input_token_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.output_order_vault.to_account_info(),
+        ctx.accounts.owner_output_token_account.to_account_info(),
+        ctx.accounts.output_token_mint.to_account_info(),
+        ctx.accounts.output_token_program.to_account_info(),
+        long_term_order.bought_amount,
+        ctx.accounts.output_token_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(LongTermOrderEvent {
+        order_id: long_term_order.key(),
+        owner: long_term_order.owner,
+        pool_id: long_term_order.pool_id,
+        input_mint: long_term_order.input_mint,
+        output_mint: long_term_order.output_mint,
+        amount_in: unsold_amount,
+        amount_out: long_term_order.bought_amount,
+        sold_amount: long_term_order.sold_amount,
+        bought_amount: long_term_order.bought_amount,
+        change_type: 2,
+    });
+    Ok(())
+}
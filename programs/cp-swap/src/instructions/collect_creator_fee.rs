@@ -55,11 +55,15 @@ pub struct CollectCreatorFee<'info> {
     )]
     pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
 
+    /// CHECK: the creator fee recipient, the pool creator unless set otherwise
+    #[account(address = pool_state.load()?.get_creator_fee_recipient())]
+    pub creator_fee_recipient: UncheckedAccount<'info>,
+
     /// The address that receives the collected token_0 fund fees
     #[account(
         init_if_needed,
         associated_token::mint = vault_0_mint,
-        associated_token::authority = creator,
+        associated_token::authority = creator_fee_recipient,
         payer = creator,
         associated_token::token_program = token_0_program,
     )]
@@ -69,7 +73,7 @@ pub struct CollectCreatorFee<'info> {
     #[account(
         init_if_needed,
         associated_token::mint = vault_1_mint,
-        associated_token::authority = creator,
+        associated_token::authority = creator_fee_recipient,
         payer = creator,
         associated_token::token_program = token_1_program,
     )]
@@ -85,7 +89,9 @@ pub struct CollectCreatorFee<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn collect_creator_fee(ctx: Context<CollectCreatorFee>) -> Result<()> {
+pub fn collect_creator_fee<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectCreatorFee<'info>>,
+) -> Result<()> {
     let mut pool_state = ctx.accounts.pool_state.load_mut()?;
     let creator_fees_token_0 = pool_state.creator_fees_token_0;
     let creator_fees_token_1 = pool_state.creator_fees_token_1;
@@ -106,6 +112,7 @@ pub fn collect_creator_fee(ctx: Context<CollectCreatorFee>) -> Result<()> {
         creator_fees_token_0,
         ctx.accounts.vault_0_mint.decimals,
         &[signer_seeds],
+        ctx.remaining_accounts,
     )?;
 
     transfer_from_pool_vault_to_user(
@@ -117,6 +124,7 @@ pub fn collect_creator_fee(ctx: Context<CollectCreatorFee>) -> Result<()> {
         creator_fees_token_1,
         ctx.accounts.vault_1_mint.decimals,
         &[signer_seeds],
+        ctx.remaining_accounts,
     )?;
 
     pool_state.creator_fees_token_0 = 0;
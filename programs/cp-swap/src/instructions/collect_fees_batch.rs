@@ -0,0 +1,207 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::Token2022;
+use anchor_spl::token_interface::TokenAccount;
+
+/// Number of remaining accounts of each pool in `collect_fees_batch`
+pub const COLLECT_FEES_BATCH_ACCOUNTS_PER_POOL: usize = 9;
+/// Compute units reserved to sweep a pool, the batch stops before a pool once fewer remain
+pub const COLLECT_FEES_BATCH_MIN_COMPUTE_UNITS: u64 = 60_000;
+
+#[derive(Accounts)]
+pub struct CollectFeesBatch<'info> {
+    /// Anyone can sweep the fees, they can only go to the owners' token accounts
+    pub payer: Signer<'info>,
+
+    /// CHECK: pool vault and lp mint authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Amm config account stores protocol_owner and fund_owner
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// CHECK: the protocol fee distribution of the amm config, when it exists the protocol fees
+    /// are left to `distribute_fees`
+    #[account(
+        seeds = [
+            FEE_DISTRIBUTION_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+            &[FeeDistributionKind::Protocol as u8],
+        ],
+        bump,
+    )]
+    pub protocol_fee_distribution: UncheckedAccount<'info>,
+
+    /// CHECK: the fund fee distribution of the amm config, when it exists the fund fees
+    /// are left to `distribute_fees`
+    #[account(
+        seeds = [
+            FEE_DISTRIBUTION_SEED.as_bytes(),
+            amm_config.key().as_ref(),
+            &[FeeDistributionKind::Fund as u8],
+        ],
+        bump,
+    )]
+    pub fund_fee_distribution: UncheckedAccount<'info>,
+
+    /// The SPL program to perform token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// The SPL program 2022 to perform token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+}
+
+/// Sweep the protocol and fund fees of many pools under the amm config.
+/// remaining_accounts, for each pool: pool_state, token_0_vault, token_1_vault, vault_0_mint, vault_1_mint,
+/// protocol_owner token_0 account, protocol_owner token_1 account, fund_owner token_0 account, fund_owner token_1 account.
+/// Pools with transfer hook mints are skipped, they must be collected one by one with
+/// `collect_protocol_fee` / `collect_fund_fee` which take the hook accounts.
+/// The fees of a kind with a `FeeDistribution` are not collected, they are only paid out by `distribute_fees`.
+pub fn collect_fees_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CollectFeesBatch<'info>>,
+) -> Result<()> {
+    let pools = ctx
+        .remaining_accounts
+        .chunks_exact(COLLECT_FEES_BATCH_ACCOUNTS_PER_POOL);
+    require!(
+        !ctx.remaining_accounts.is_empty() && pools.remainder().is_empty(),
+        ErrorCode::InvalidInput
+    );
+
+    for pool_accounts in pools {
+        if sol_remaining_compute_units() < COLLECT_FEES_BATCH_MIN_COMPUTE_UNITS {
+            #[cfg(feature = "enable-log")]
+            msg!(
+                "compute units exhausted, stop at pool:{}",
+                pool_accounts[0].key()
+            );
+            break;
+        }
+        collect_pool_fees(&ctx, pool_accounts)?;
+    }
+    Ok(())
+}
+
+fn collect_pool_fees<'info>(
+    ctx: &Context<'_, '_, 'info, 'info, CollectFeesBatch<'info>>,
+    pool_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let pool_loader = AccountLoader::<PoolState>::try_from(&pool_accounts[0])?;
+    let vaults = [&pool_accounts[1], &pool_accounts[2]];
+    let vault_mints = [
+        InterfaceAccount::<Mint>::try_from(&pool_accounts[3])?,
+        InterfaceAccount::<Mint>::try_from(&pool_accounts[4])?,
+    ];
+    let protocol_fee_accounts = [&pool_accounts[5], &pool_accounts[6]];
+    let fund_fee_accounts = [&pool_accounts[7], &pool_accounts[8]];
+
+    if get_transfer_hook_program_id(&vault_mints[0].to_account_info())?.is_some()
+        || get_transfer_hook_program_id(&vault_mints[1].to_account_info())?.is_some()
+    {
+        #[cfg(feature = "enable-log")]
+        msg!("transfer hook mint, skip pool:{}", pool_loader.key());
+        return Ok(());
+    }
+    let collect_protocol_fees = ctx.accounts.protocol_fee_distribution.data_is_empty();
+    let collect_fund_fees = ctx.accounts.fund_fee_distribution.data_is_empty();
+
+    let mut protocol_fees = [0u64; 2];
+    let mut fund_fees = [0u64; 2];
+    let auth_bump: u8;
+    {
+        let mut pool_state = pool_loader.load_mut()?;
+        require_keys_eq!(
+            pool_state.amm_config,
+            ctx.accounts.amm_config.key(),
+            ErrorCode::InvalidAmmConfig
+        );
+        require_keys_eq!(vaults[0].key(), pool_state.token_0_vault);
+        require_keys_eq!(vaults[1].key(), pool_state.token_1_vault);
+        require_keys_eq!(vault_mints[0].key(), pool_state.token_0_mint);
+        require_keys_eq!(vault_mints[1].key(), pool_state.token_1_mint);
+
+        if collect_protocol_fees {
+            protocol_fees = [
+                pool_state.protocol_fees_token_0,
+                pool_state.protocol_fees_token_1,
+            ];
+            pool_state.protocol_fees_token_0 = 0;
+            pool_state.protocol_fees_token_1 = 0;
+        }
+        if collect_fund_fees {
+            fund_fees = [pool_state.fund_fees_token_0, pool_state.fund_fees_token_1];
+            pool_state.fund_fees_token_0 = 0;
+            pool_state.fund_fees_token_1 = 0;
+        }
+        if protocol_fees == [0, 0] && fund_fees == [0, 0] {
+            return Ok(());
+        }
+        auth_bump = pool_state.auth_bump;
+        pool_state.recent_epoch = Clock::get()?.epoch;
+    }
+
+    for i in 0..2 {
+        let vault_mint = &vault_mints[i];
+        let token_program = if vault_mint.to_account_info().owner == ctx.accounts.token_program.key
+        {
+            ctx.accounts.token_program.to_account_info()
+        } else {
+            ctx.accounts.token_program_2022.to_account_info()
+        };
+        for (recipient_account_info, fee_owner, amount) in [
+            (
+                protocol_fee_accounts[i],
+                ctx.accounts.amm_config.protocol_owner,
+                protocol_fees[i],
+            ),
+            (
+                fund_fee_accounts[i],
+                ctx.accounts.amm_config.fund_owner,
+                fund_fees[i],
+            ),
+        ] {
+            if amount == 0 {
+                continue;
+            }
+            let recipient_account =
+                InterfaceAccount::<TokenAccount>::try_from(recipient_account_info)?;
+            require_keys_eq!(recipient_account.owner, fee_owner, ErrorCode::InvalidOwner);
+            require_keys_eq!(
+                recipient_account.mint,
+                vault_mint.key(),
+                ErrorCode::InvalidInput
+            );
+
+            transfer_from_pool_vault_to_user(
+                ctx.accounts.authority.to_account_info(),
+                vaults[i].clone(),
+                recipient_account_info.clone(),
+                vault_mint.to_account_info(),
+                token_program.clone(),
+                amount,
+                vault_mint.decimals,
+                &[&[crate::AUTH_SEED.as_bytes(), &[auth_bump]]],
+                &[],
+            )?;
+        }
+    }
+
+    emit!(CollectFeesEvent {
+        pool_id: pool_loader.key(),
+        protocol_fees_token_0: protocol_fees[0],
+        protocol_fees_token_1: protocol_fees[1],
+        fund_fees_token_0: fund_fees[0],
+        fund_fees_token_1: fund_fees[1],
+    });
+    Ok(())
+}
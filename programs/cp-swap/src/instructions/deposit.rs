@@ -76,16 +76,23 @@ pub struct Deposit<'info> {
     )]
     pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// Lp token mint
+    /// Lp token mint, owned by token program or token program 2022
     #[account(
         mut,
         address = pool_state.load()?.lp_mint @ ErrorCode::IncorrectLpMint)
     ]
     pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Protocol state holding the protocol-wide status
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
 }
 
-pub fn deposit(
-    ctx: Context<Deposit>,
+pub fn deposit<'info>(
+    ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
     lp_token_amount: u64,
     maximum_token_0_amount: u64,
     maximum_token_1_amount: u64,
@@ -93,7 +100,12 @@ pub fn deposit(
     require_gt!(lp_token_amount, 0);
     let pool_id = ctx.accounts.pool_state.key();
     let pool_state = &mut ctx.accounts.pool_state.load_mut()?;
-    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Deposit) {
+    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Deposit)
+        || !ctx
+            .accounts
+            .protocol_state
+            .get_status_by_bit(PoolStatusBitIndex::Deposit)
+    {
         return err!(ErrorCode::NotApproved);
     }
     let (total_token_0_amount, total_token_1_amount) = pool_state.vault_amount_without_fee(
@@ -173,6 +185,7 @@ pub fn deposit(
         },
         transfer_token_0_amount,
         ctx.accounts.vault_0_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
     transfer_from_user_to_pool_vault(
@@ -187,13 +200,18 @@ pub fn deposit(
         },
         transfer_token_1_amount,
         ctx.accounts.vault_1_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
     pool_state.lp_supply = pool_state.lp_supply.checked_add(lp_token_amount).unwrap();
 
     token_mint_to(
         ctx.accounts.authority.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
+        if ctx.accounts.lp_mint.to_account_info().owner == ctx.accounts.token_program.key {
+            ctx.accounts.token_program.to_account_info()
+        } else {
+            ctx.accounts.token_program_2022.to_account_info()
+        },
         ctx.accounts.lp_mint.to_account_info(),
         ctx.accounts.owner_lp_token.to_account_info(),
         lp_token_amount,
@@ -201,5 +219,14 @@ pub fn deposit(
     )?;
     pool_state.recent_epoch = Clock::get()?.epoch;
 
-    Ok(())
+    LpChangeReturnData {
+        lp_token_amount,
+        token_0_amount,
+        token_1_amount,
+        token_0_transfer_fee: transfer_token_0_fee,
+        token_1_transfer_fee: transfer_token_1_fee,
+        new_token_0_vault_amount: total_token_0_amount.checked_add(token_0_amount).unwrap(),
+        new_token_1_vault_amount: total_token_1_amount.checked_add(token_1_amount).unwrap(),
+    }
+    .set()
 }
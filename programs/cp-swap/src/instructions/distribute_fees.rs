@@ -0,0 +1,168 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::Mint;
+use anchor_spl::token_interface::Token2022;
+use anchor_spl::token_interface::TokenAccount;
+
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    /// Anyone can crank the distribution
+    pub payer: Signer<'info>,
+
+    /// CHECK: pool vault and lp mint authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// Pool state stores accumulated protocol and fund fee amount
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The fee distribution of the pool's amm config
+    #[account(
+        seeds = [
+            FEE_DISTRIBUTION_SEED.as_bytes(),
+            pool_state.load()?.amm_config.as_ref(),
+            &[fee_distribution.fee_kind],
+        ],
+        bump = fee_distribution.bump,
+    )]
+    pub fee_distribution: Box<Account<'info, FeeDistribution>>,
+
+    /// The address that holds pool tokens for token_0
+    #[account(
+        mut,
+        constraint = token_0_vault.key() == pool_state.load()?.token_0_vault
+    )]
+    pub token_0_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds pool tokens for token_1
+    #[account(
+        mut,
+        constraint = token_1_vault.key() == pool_state.load()?.token_1_vault
+    )]
+    pub token_1_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token_0 vault
+    #[account(
+        address = token_0_vault.mint
+    )]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token_1 vault
+    #[account(
+        address = token_1_vault.mint
+    )]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The SPL program to perform token transfers
+    pub token_program: Program<'info, Token>,
+
+    /// The SPL program 2022 to perform token transfers
+    pub token_program_2022: Program<'info, Token2022>,
+}
+
+/// Collect all the protocol or fund fees of the pool and split them between the recipients.
+/// remaining_accounts: the token_0 and token_1 accounts of each recipient in order,
+/// followed by the transfer hook accounts if any
+pub fn distribute_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DistributeFees<'info>>,
+) -> Result<()> {
+    let fee_distribution = &ctx.accounts.fee_distribution;
+    let recipients = fee_distribution.recipients();
+    let recipient_accounts_len = recipients.len() * 2;
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        recipient_accounts_len,
+        ErrorCode::InvalidInput
+    );
+    let (recipient_accounts, hook_accounts) =
+        ctx.remaining_accounts.split_at(recipient_accounts_len);
+
+    let amount_0: u64;
+    let amount_1: u64;
+    let auth_bump: u8;
+    {
+        let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+        if fee_distribution.fee_kind == FeeDistributionKind::Protocol as u8 {
+            amount_0 = pool_state.protocol_fees_token_0;
+            amount_1 = pool_state.protocol_fees_token_1;
+            pool_state.protocol_fees_token_0 = 0;
+            pool_state.protocol_fees_token_1 = 0;
+        } else {
+            amount_0 = pool_state.fund_fees_token_0;
+            amount_1 = pool_state.fund_fees_token_1;
+            pool_state.fund_fees_token_0 = 0;
+            pool_state.fund_fees_token_1 = 0;
+        }
+        if amount_0 == 0 && amount_1 == 0 {
+            return err!(ErrorCode::NoFeeCollect);
+        }
+        auth_bump = pool_state.auth_bump;
+        pool_state.recent_epoch = Clock::get()?.epoch;
+    }
+
+    let vaults = [
+        (
+            &ctx.accounts.token_0_vault,
+            &ctx.accounts.vault_0_mint,
+            amount_0,
+        ),
+        (
+            &ctx.accounts.token_1_vault,
+            &ctx.accounts.vault_1_mint,
+            amount_1,
+        ),
+    ];
+    for (i, (vault, vault_mint, amount)) in vaults.into_iter().enumerate() {
+        let token_program = if vault_mint.to_account_info().owner == ctx.accounts.token_program.key
+        {
+            ctx.accounts.token_program.to_account_info()
+        } else {
+            ctx.accounts.token_program_2022.to_account_info()
+        };
+        let shares = fee_distribution.split_amount(amount);
+        for (j, recipient) in recipients.iter().enumerate() {
+            let recipient_account_info = &recipient_accounts[j * 2 + i];
+            let recipient_account =
+                InterfaceAccount::<TokenAccount>::try_from(recipient_account_info)?;
+            require_keys_eq!(
+                recipient_account.owner,
+                recipient.owner,
+                ErrorCode::InvalidOwner
+            );
+            require_keys_eq!(
+                recipient_account.mint,
+                vault_mint.key(),
+                ErrorCode::InvalidInput
+            );
+
+            transfer_from_pool_vault_to_user(
+                ctx.accounts.authority.to_account_info(),
+                vault.to_account_info(),
+                recipient_account_info.clone(),
+                vault_mint.to_account_info(),
+                token_program.clone(),
+                shares[j],
+                vault_mint.decimals,
+                &[&[crate::AUTH_SEED.as_bytes(), &[auth_bump]]],
+                hook_accounts,
+            )?;
+        }
+    }
+
+    emit!(FeeDistributedEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        fee_kind: fee_distribution.fee_kind,
+        amount_0,
+        amount_1,
+    });
+    Ok(())
+}
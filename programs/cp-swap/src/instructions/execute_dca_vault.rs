@@ -0,0 +1,175 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::swap::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct ExecuteDcaVault<'info> {
+    /// CHECK: pool vault and DCA token vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The DCA vault
+    #[account(mut)]
+    pub dca_vault: Box<Account<'info, DcaVault>>,
+
+    /// The factory state to read fee rates
+    #[account(address = pool_state.load()?.amm_config)]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The pool the DCA vault swaps through
+    #[account(mut, address = dca_vault.pool_id)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The vault token account for input token
+    #[account(
+        mut,
+        constraint = input_vault.key() == pool_state.load()?.token_0_vault || input_vault.key() == pool_state.load()?.token_1_vault
+    )]
+    pub input_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    #[account(
+        mut,
+        constraint = output_vault.key() == pool_state.load()?.token_0_vault || output_vault.key() == pool_state.load()?.token_1_vault
+    )]
+    pub output_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of input token
+    #[account(address = dca_vault.input_mint)]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of output token
+    #[account(address = dca_vault.output_mint)]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The token vault holding the input tokens of all DCA vaults
+    #[account(
+        mut,
+        seeds = [
+            DCA_TOKEN_VAULT_SEED.as_bytes(),
+            input_token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub input_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token vault holding the bought tokens of all DCA vaults
+    #[account(
+        mut,
+        seeds = [
+            DCA_TOKEN_VAULT_SEED.as_bytes(),
+            output_token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub output_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program for input token transfers
+    pub input_token_program: Interface<'info, TokenInterface>,
+
+    /// SPL program for output token transfers
+    pub output_token_program: Interface<'info, TokenInterface>,
+
+    /// The program account for the most recent oracle observation
+    #[account(mut, address = pool_state.load()?.observation_key)]
+    pub observation_state: AccountLoader<'info, ObservationState>,
+
+    /// Protocol state holding the protocol-wide status
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+}
+
+/// Sell the input tokens of the current period of a DCA vault through the pool
+pub fn execute_dca_vault<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteDcaVault<'info>>,
+) -> Result<()> {
+    let block_timestamp = Clock::get()?.unix_timestamp as u64;
+    let auth_signer_seeds: &[&[u8]] = &[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]];
+    let pool_id = ctx.accounts.pool_state.key();
+    let pool_state = &mut ctx.accounts.pool_state.load_mut()?;
+    let amount_in = ctx.accounts.dca_vault.amount_due(block_timestamp);
+    require_gt!(amount_in, 0, ErrorCode::DcaVaultNotDue);
+    let swap_result = swap_base_input_in_pool(
+        pool_state,
+        &PoolSwapAccounts {
+            pool_id,
+            amm_config: &ctx.accounts.amm_config,
+            protocol_state: &ctx.accounts.protocol_state,
+            input_vault: &ctx.accounts.input_vault,
+            output_vault: &ctx.accounts.output_vault,
+            input_token_mint: &ctx.accounts.input_token_mint,
+            output_token_mint: &ctx.accounts.output_token_mint,
+            observation_state: &ctx.accounts.observation_state,
+        },
+        amount_in,
+    )?;
+    // the execution price of the input token must not fall below its twap beyond the vault deviation
+    swap_result.check_twap_deviation(
+        pool_state,
+        &*ctx.accounts.observation_state.load()?,
+        block_timestamp,
+        DCA_TWAP_WINDOW,
+        ctx.accounts.dca_vault.max_twap_deviation_bps,
+    )?;
+    let amount_out = swap_result.amount_out;
+    let amount_received = swap_result.amount_received();
+
+    // move the input tokens of the period into the pool
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.input_token_vault.to_account_info(),
+        ctx.accounts.input_vault.to_account_info(),
+        ctx.accounts.input_token_mint.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        amount_in,
+        ctx.accounts.input_token_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    // the bought tokens are credited to the DCA vault until it is closed
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.output_vault.to_account_info(),
+        ctx.accounts.output_token_vault.to_account_info(),
+        ctx.accounts.output_token_mint.to_account_info(),
+        ctx.accounts.output_token_program.to_account_info(),
+        amount_out,
+        ctx.accounts.output_token_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    let dca_vault = ctx.accounts.dca_vault.as_mut();
+    dca_vault.input_amount -= amount_in;
+    dca_vault.output_amount = dca_vault
+        .output_amount
+        .checked_add(amount_received)
+        .ok_or(ErrorCode::MathOverflow)?;
+    dca_vault.advance(block_timestamp);
+
+    emit!(DcaVaultEvent {
+        dca_vault: ctx.accounts.dca_vault.key(),
+        owner: ctx.accounts.dca_vault.owner,
+        pool_id,
+        input_mint: ctx.accounts.dca_vault.input_mint,
+        output_mint: ctx.accounts.dca_vault.output_mint,
+        amount_in,
+        amount_out: amount_received,
+        input_amount: ctx.accounts.dca_vault.input_amount,
+        output_amount: ctx.accounts.dca_vault.output_amount,
+        change_type: 1,
+    });
+    Ok(())
+}
@@ -0,0 +1,194 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::swap::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct FillLimitOrder<'info> {
+    /// The keeper filling the order, receives the keeper bounty
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// CHECK: pool vault and order vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: the owner of the order, receives the rent back
+    #[account(mut, address = limit_order.owner)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// The order
+    #[account(
+        mut,
+        close = owner,
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    /// The factory state to read fee rates
+    #[account(address = pool_state.load()?.amm_config)]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The pool the order is filled against
+    #[account(mut, address = limit_order.pool_id)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The vault token account for input token
+    #[account(
+        mut,
+        constraint = input_vault.key() == pool_state.load()?.token_0_vault || input_vault.key() == pool_state.load()?.token_1_vault
+    )]
+    pub input_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    #[account(
+        mut,
+        constraint = output_vault.key() == pool_state.load()?.token_0_vault || output_vault.key() == pool_state.load()?.token_1_vault
+    )]
+    pub output_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of input token
+    #[account(
+        address = input_vault.mint,
+        constraint = input_token_mint.key() == limit_order.input_mint @ ErrorCode::InvalidInput
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of output token
+    #[account(address = output_vault.mint)]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The vault escrowing the input tokens of all orders
+    #[account(
+        mut,
+        seeds = [
+            LIMIT_ORDER_VAULT_SEED.as_bytes(),
+            input_token_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The owner token account receiving the output token
+    #[account(
+        mut,
+        token::mint = output_token_mint,
+        token::authority = limit_order.owner,
+    )]
+    pub owner_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// SPL program for input token transfers
+    pub input_token_program: Interface<'info, TokenInterface>,
+
+    /// SPL program for output token transfers
+    pub output_token_program: Interface<'info, TokenInterface>,
+
+    /// The program account for the most recent oracle observation
+    #[account(mut, address = pool_state.load()?.observation_key)]
+    pub observation_state: AccountLoader<'info, ObservationState>,
+
+    /// Protocol state holding the protocol-wide status
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+}
+
+/// Fill a limit order through the pool once the swap gets the target price for its input token
+pub fn fill_limit_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillLimitOrder<'info>>,
+) -> Result<()> {
+    let auth_signer_seeds: &[&[u8]] = &[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]];
+    let pool_id = ctx.accounts.pool_state.key();
+    let pool_state = &mut ctx.accounts.pool_state.load_mut()?;
+    let amount_in = ctx.accounts.limit_order.amount_in;
+    let swap_result = swap_base_input_in_pool(
+        pool_state,
+        &PoolSwapAccounts {
+            pool_id,
+            amm_config: &ctx.accounts.amm_config,
+            protocol_state: &ctx.accounts.protocol_state,
+            input_vault: &ctx.accounts.input_vault,
+            output_vault: &ctx.accounts.output_vault,
+            input_token_mint: &ctx.accounts.input_token_mint,
+            output_token_mint: &ctx.accounts.output_token_mint,
+            observation_state: &ctx.accounts.observation_state,
+        },
+        amount_in,
+    )?;
+    let amount_out = swap_result.amount_out;
+    let amount_received = swap_result.amount_received();
+    require!(
+        ctx.accounts
+            .limit_order
+            .is_fillable(amount_in - swap_result.input_transfer_fee, amount_received),
+        ErrorCode::LimitOrderNotFillable
+    );
+    require_gte!(
+        amount_received,
+        ctx.accounts.limit_order.minimum_amount_out,
+        ErrorCode::ExceededSlippage
+    );
+
+    // move the escrowed tokens into the pool
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.order_vault.to_account_info(),
+        ctx.accounts.input_vault.to_account_info(),
+        ctx.accounts.input_token_mint.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        amount_in,
+        ctx.accounts.input_token_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.output_vault.to_account_info(),
+        ctx.accounts.owner_output_token_account.to_account_info(),
+        ctx.accounts.output_token_mint.to_account_info(),
+        ctx.accounts.output_token_program.to_account_info(),
+        amount_out,
+        ctx.accounts.output_token_mint.decimals,
+        &[auth_signer_seeds],
+        ctx.remaining_accounts,
+    )?;
+
+    // pay the keeper bounty, the rest of the order lamports go back to the owner on close
+    let keeper_bounty = ctx.accounts.limit_order.keeper_bounty;
+    if keeper_bounty > 0 {
+        let order_info = ctx.accounts.limit_order.to_account_info();
+        let keeper_info = ctx.accounts.keeper.to_account_info();
+        **order_info.try_borrow_mut_lamports()? = order_info
+            .lamports()
+            .checked_sub(keeper_bounty)
+            .ok_or(ErrorCode::MathOverflow)?;
+        **keeper_info.try_borrow_mut_lamports()? = keeper_info
+            .lamports()
+            .checked_add(keeper_bounty)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    let limit_order = &ctx.accounts.limit_order;
+    emit!(LimitOrderEvent {
+        order_id: limit_order.key(),
+        owner: limit_order.owner,
+        pool_id,
+        input_mint: limit_order.input_mint,
+        output_mint: limit_order.output_mint,
+        amount_in,
+        amount_out: amount_received,
+        target_price_x32: limit_order.target_price_x32,
+        keeper_bounty,
+        change_type: 2,
+    });
+    Ok(())
+}
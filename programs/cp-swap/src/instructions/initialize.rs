@@ -9,10 +9,10 @@ use anchor_lang::{
     system_program,
 };
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{self, AssociatedToken},
     token::spl_token,
     token::Token,
-    token_interface::{Mint, TokenAccount, TokenInterface},
+    token_interface::{Mint, Token2022, TokenAccount, TokenInterface},
 };
 use anchor_spl::token_interface::spl_token_2022;
 use std::ops::Deref;
@@ -62,20 +62,17 @@ pub struct Initialize<'info> {
     )]
     pub token_1_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// pool lp mint
+    /// CHECK: pool lp mint, created by contract under token program or
+    /// token program 2022 when lp mint metadata is provided
     #[account(
-        init,
+        mut,
         seeds = [
             POOL_LP_MINT_SEED.as_bytes(),
             pool_state.key().as_ref(),
         ],
         bump,
-        mint::decimals = 9,
-        mint::authority = authority,
-        payer = creator,
-        mint::token_program = token_program,
     )]
-    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub lp_mint: UncheckedAccount<'info>,
 
     /// payer token0 account
     #[account(
@@ -93,15 +90,9 @@ pub struct Initialize<'info> {
     )]
     pub creator_token_1: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// creator lp token account
-    #[account(
-        init,
-        associated_token::mint = lp_mint,
-        associated_token::authority = creator,
-        payer = creator,
-        token::token_program = token_program,
-    )]
-    pub creator_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: creator lp token account, the associated token account is created by contract
+    #[account(mut)]
+    pub creator_lp_token: UncheckedAccount<'info>,
 
     /// CHECK: Token_0 vault for the pool, created by contract
     #[account(
@@ -149,6 +140,8 @@ pub struct Initialize<'info> {
 
     /// Program to create mint account and mint tokens
     pub token_program: Program<'info, Token>,
+    /// Program to create the lp mint with token metadata
+    pub token_program_2022: Program<'info, Token2022>,
     /// Spl token program or token program 2022
     pub token_0_program: Interface<'info, TokenInterface>,
     /// Spl token program or token program 2022
@@ -161,15 +154,22 @@ pub struct Initialize<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn initialize(
-    ctx: Context<Initialize>,
+pub fn initialize<'info>(
+    ctx: Context<'_, '_, '_, 'info, Initialize<'info>>,
     init_amount_0: u64,
     init_amount_1: u64,
     mut open_time: u64,
+    lp_mint_metadata: Option<LpMintMetadata>,
 ) -> Result<()> {
-    if !(is_supported_mint(&ctx.accounts.token_0_mint).unwrap()
-        && is_supported_mint(&ctx.accounts.token_1_mint).unwrap())
-    {
+    if !(is_supported_mint(
+        &ctx.accounts.token_0_mint,
+        &ctx.accounts.amm_config,
+        ctx.remaining_accounts,
+    )? && is_supported_mint(
+        &ctx.accounts.token_1_mint,
+        &ctx.accounts.amm_config,
+        ctx.remaining_accounts,
+    )?) {
         return err!(ErrorCode::NotSupportMint);
     }
 
@@ -221,6 +221,40 @@ pub fn initialize(
     )?;
     let pool_state = &mut pool_state_loader.load_init()?;
 
+    let lp_token_program = if lp_mint_metadata.is_some() {
+        ctx.accounts.token_program_2022.to_account_info()
+    } else {
+        ctx.accounts.token_program.to_account_info()
+    };
+    create_lp_mint(
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.creator.to_account_info(),
+        &ctx.accounts.lp_mint.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &lp_token_program,
+        LP_MINT_DECIMALS,
+        lp_mint_metadata
+            .map(|metadata| metadata.to_token_metadata())
+            .transpose()?,
+        &[
+            POOL_LP_MINT_SEED.as_bytes(),
+            ctx.accounts.pool_state.key().as_ref(),
+            &[ctx.bumps.lp_mint][..],
+        ],
+        &[&[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]]],
+    )?;
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: ctx.accounts.creator.to_account_info(),
+            associated_token: ctx.accounts.creator_lp_token.to_account_info(),
+            authority: ctx.accounts.creator.to_account_info(),
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: lp_token_program.clone(),
+        },
+    ))?;
+
     let mut observation_state = ctx.accounts.observation_state.load_init()?;
     observation_state.pool_id = ctx.accounts.pool_state.key();
 
@@ -232,6 +266,7 @@ pub fn initialize(
         ctx.accounts.token_0_program.to_account_info(),
         init_amount_0,
         ctx.accounts.token_0_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
     transfer_from_user_to_pool_vault(
@@ -242,6 +277,7 @@ pub fn initialize(
         ctx.accounts.token_1_program.to_account_info(),
         init_amount_1,
         ctx.accounts.token_1_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
     let token_0_vault =
@@ -280,7 +316,7 @@ pub fn initialize(
     );
     token::token_mint_to(
         ctx.accounts.authority.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
+        lp_token_program,
         ctx.accounts.lp_mint.to_account_info(),
         ctx.accounts.creator_lp_token.to_account_info(),
         liquidity
@@ -326,7 +362,7 @@ pub fn initialize(
         &ctx.accounts.token_0_mint,
         &ctx.accounts.token_1_mint,
         ctx.accounts.lp_mint.key(),
-        ctx.accounts.lp_mint.decimals,
+        LP_MINT_DECIMALS,
         ctx.accounts.observation_state.key(),
         CreatorFeeOn::BothToken,
         false,
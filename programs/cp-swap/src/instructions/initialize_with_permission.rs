@@ -9,10 +9,10 @@ use anchor_lang::{
     solana_program::{clock, program::invoke, system_instruction},
 };
 use anchor_spl::{
-    associated_token::AssociatedToken,
+    associated_token::{self, AssociatedToken},
     token::spl_token,
     token::Token,
-    token_interface::{Mint, TokenAccount, TokenInterface},
+    token_interface::{Mint, Token2022, TokenAccount, TokenInterface},
 };
 use anchor_spl::token_interface::spl_token_2022;
 use std::ops::Deref;
@@ -65,20 +65,17 @@ pub struct InitializeWithPermission<'info> {
     )]
     pub token_1_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// pool lp mint
+    /// CHECK: pool lp mint, created by contract under token program or
+    /// token program 2022 when lp mint metadata is provided
     #[account(
-        init,
+        mut,
         seeds = [
             POOL_LP_MINT_SEED.as_bytes(),
             pool_state.key().as_ref(),
         ],
         bump,
-        mint::decimals = 9,
-        mint::authority = authority,
-        payer = payer,
-        mint::token_program = token_program,
     )]
-    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub lp_mint: UncheckedAccount<'info>,
 
     /// payer token0 account
     #[account(
@@ -96,15 +93,9 @@ pub struct InitializeWithPermission<'info> {
     )]
     pub payer_token_1: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// payer lp token account
-    #[account(
-        init,
-        associated_token::mint = lp_mint,
-        associated_token::authority = payer,
-        payer = payer,
-        token::token_program = token_program,
-    )]
-    pub payer_lp_token: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: payer lp token account, the associated token account is created by contract
+    #[account(mut)]
+    pub payer_lp_token: UncheckedAccount<'info>,
 
     /// CHECK: Token_0 vault for the pool, created by contract
     #[account(
@@ -152,6 +143,7 @@ pub struct InitializeWithPermission<'info> {
 
     /// CHECK: PDA account used for permission verification.
     #[account(
+        mut,
         seeds = [
             PERMISSION_SEED.as_bytes(),
             payer.key().as_ref(),
@@ -162,6 +154,8 @@ pub struct InitializeWithPermission<'info> {
 
     /// Program to create mint account and mint tokens
     pub token_program: Program<'info, Token>,
+    /// Program to create the lp mint with token metadata
+    pub token_program_2022: Program<'info, Token2022>,
     /// Spl token program or token program 2022
     pub token_0_program: Interface<'info, TokenInterface>,
     /// Spl token program or token program 2022
@@ -172,16 +166,24 @@ pub struct InitializeWithPermission<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn initialize_with_permission(
-    ctx: Context<InitializeWithPermission>,
+pub fn initialize_with_permission<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitializeWithPermission<'info>>,
     init_amount_0: u64,
     init_amount_1: u64,
     open_time: u64,
     creator_fee_on: CreatorFeeOn,
+    enable_creator_fee: bool,
+    lp_mint_metadata: Option<LpMintMetadata>,
 ) -> Result<()> {
-    if !(is_supported_mint(&ctx.accounts.token_0_mint).unwrap()
-        && is_supported_mint(&ctx.accounts.token_1_mint).unwrap())
-    {
+    if !(is_supported_mint(
+        &ctx.accounts.token_0_mint,
+        &ctx.accounts.amm_config,
+        ctx.remaining_accounts,
+    )? && is_supported_mint(
+        &ctx.accounts.token_1_mint,
+        &ctx.accounts.amm_config,
+        ctx.remaining_accounts,
+    )?) {
         return err!(ErrorCode::NotSupportMint);
     }
 
@@ -190,6 +192,19 @@ pub fn initialize_with_permission(
     }
     let mut open_time = open_time;
     let block_timestamp = clock::Clock::get()?.unix_timestamp as u64;
+
+    let permission = ctx.accounts.permission.as_mut();
+    permission.check_create_pool(ctx.accounts.amm_config.index, block_timestamp)?;
+    if (enable_creator_fee
+        && !permission.get_privilege_by_bit(PermissionPrivilegeBitIndex::EnableCreatorFee))
+        || (creator_fee_on != CreatorFeeOn::BothToken
+            && !permission.get_privilege_by_bit(PermissionPrivilegeBitIndex::CreatorFeeOn))
+    {
+        return err!(ErrorCode::PermissionPrivilegeNotAllowed);
+    }
+    let creator_fee_on_locked =
+        !permission.get_privilege_by_bit(PermissionPrivilegeBitIndex::CreatorFeeOn);
+    permission.pool_count = permission.pool_count.checked_add(1).unwrap();
     if open_time <= block_timestamp {
         open_time = block_timestamp + 1;
     }
@@ -234,6 +249,40 @@ pub fn initialize_with_permission(
     )?;
     let pool_state = &mut pool_state_loader.load_init()?;
 
+    let lp_token_program = if lp_mint_metadata.is_some() {
+        ctx.accounts.token_program_2022.to_account_info()
+    } else {
+        ctx.accounts.token_program.to_account_info()
+    };
+    create_lp_mint(
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.lp_mint.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &lp_token_program,
+        LP_MINT_DECIMALS,
+        lp_mint_metadata
+            .map(|metadata| metadata.to_token_metadata())
+            .transpose()?,
+        &[
+            POOL_LP_MINT_SEED.as_bytes(),
+            ctx.accounts.pool_state.key().as_ref(),
+            &[ctx.bumps.lp_mint][..],
+        ],
+        &[&[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]]],
+    )?;
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: ctx.accounts.payer.to_account_info(),
+            associated_token: ctx.accounts.payer_lp_token.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+            mint: ctx.accounts.lp_mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: lp_token_program.clone(),
+        },
+    ))?;
+
     let mut observation_state = ctx.accounts.observation_state.load_init()?;
     observation_state.pool_id = ctx.accounts.pool_state.key();
 
@@ -245,6 +294,7 @@ pub fn initialize_with_permission(
         ctx.accounts.token_0_program.to_account_info(),
         init_amount_0,
         ctx.accounts.token_0_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
     transfer_from_user_to_pool_vault(
@@ -255,6 +305,7 @@ pub fn initialize_with_permission(
         ctx.accounts.token_1_program.to_account_info(),
         init_amount_1,
         ctx.accounts.token_1_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
     let token_0_vault =
@@ -293,7 +344,7 @@ pub fn initialize_with_permission(
     );
     token::token_mint_to(
         ctx.accounts.authority.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
+        lp_token_program,
         ctx.accounts.lp_mint.to_account_info(),
         ctx.accounts.payer_lp_token.to_account_info(),
         liquidity
@@ -339,11 +390,12 @@ pub fn initialize_with_permission(
         &ctx.accounts.token_0_mint,
         &ctx.accounts.token_1_mint,
         ctx.accounts.lp_mint.key(),
-        ctx.accounts.lp_mint.decimals,
+        LP_MINT_DECIMALS,
         ctx.accounts.observation_state.key(),
         creator_fee_on,
-        true,
+        enable_creator_fee,
     );
+    pool_state.creator_fee_on_locked = creator_fee_on_locked;
 
     Ok(())
 }
@@ -22,3 +22,66 @@ pub use collect_creator_fee::*;
 
 pub mod swap_with_k_token;
 pub use swap_with_k_token::*;
+
+pub mod transfer_pool_creator;
+pub use transfer_pool_creator::*;
+
+pub mod accept_pool_creator;
+pub use accept_pool_creator::*;
+
+pub mod set_creator_fee_recipient;
+pub use set_creator_fee_recipient::*;
+
+pub mod update_pool_creator_fee;
+pub use update_pool_creator_fee::*;
+
+pub mod distribute_fees;
+pub use distribute_fees::*;
+
+pub mod collect_fees_batch;
+pub use collect_fees_batch::*;
+
+pub mod stake;
+pub use stake::*;
+
+pub mod unstake;
+pub use unstake::*;
+
+pub mod quote_swap_base_input;
+pub use quote_swap_base_input::*;
+
+pub mod quote_swap_base_output;
+pub use quote_swap_base_output::*;
+
+pub mod quote_deposit;
+pub use quote_deposit::*;
+
+pub mod quote_withdraw;
+pub use quote_withdraw::*;
+
+pub mod place_limit_order;
+pub use place_limit_order::*;
+
+pub mod cancel_limit_order;
+pub use cancel_limit_order::*;
+
+pub mod fill_limit_order;
+pub use fill_limit_order::*;
+
+pub mod place_long_term_order;
+pub use place_long_term_order::*;
+
+pub mod execute_long_term_order;
+pub use execute_long_term_order::*;
+
+pub mod close_long_term_order;
+pub use close_long_term_order::*;
+
+pub mod open_dca_vault;
+pub use open_dca_vault::*;
+
+pub mod execute_dca_vault;
+pub use execute_dca_vault::*;
+
+pub mod close_dca_vault;
+pub use close_dca_vault::*;
@@ -0,0 +1,163 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::swap::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct OpenDcaVault<'info> {
+    /// The owner of the DCA vault
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: pool vault and DCA token vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The pool the DCA vault swaps through
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The factory state to read the trade fee rate
+    #[account(address = pool_state.load()?.amm_config)]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The mint of the token sold
+    #[account(
+        mint::token_program = input_token_program,
+        constraint = input_token_mint.key() == pool_state.load()?.token_0_mint || input_token_mint.key() == pool_state.load()?.token_1_mint @ ErrorCode::InvalidInput
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of the token bought
+    #[account(
+        mint::token_program = output_token_program,
+        constraint = output_token_mint.key() == pool_state.load()?.token_0_mint || output_token_mint.key() == pool_state.load()?.token_1_mint @ ErrorCode::InvalidInput,
+        constraint = output_token_mint.key() != input_token_mint.key() @ ErrorCode::InvalidInput
+    )]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The DCA vault
+    #[account(
+        init,
+        seeds = [
+            DCA_VAULT_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            owner.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+        payer = owner,
+        space = DcaVault::LEN
+    )]
+    pub dca_vault: Box<Account<'info, DcaVault>>,
+
+    /// The token vault holding the input tokens of all DCA vaults
+    #[account(
+        init_if_needed,
+        seeds = [
+            DCA_TOKEN_VAULT_SEED.as_bytes(),
+            input_token_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = input_token_mint,
+        token::authority = authority,
+        token::token_program = input_token_program,
+    )]
+    pub input_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The token vault holding the bought tokens of all DCA vaults
+    #[account(
+        init_if_needed,
+        seeds = [
+            DCA_TOKEN_VAULT_SEED.as_bytes(),
+            output_token_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = output_token_mint,
+        token::authority = authority,
+        token::token_program = output_token_program,
+    )]
+    pub output_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account paying the input token
+    #[account(
+        mut,
+        token::mint = input_token_mint,
+        token::authority = owner,
+    )]
+    pub owner_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022 of the input token
+    pub input_token_program: Interface<'info, TokenInterface>,
+    /// Spl token program or token program 2022 of the output token
+    pub output_token_program: Interface<'info, TokenInterface>,
+    /// To create a new program account
+    pub system_program: Program<'info, System>,
+}
+
+pub fn open_dca_vault<'info>(
+    ctx: Context<'_, '_, '_, 'info, OpenDcaVault<'info>>,
+    nonce: u64,
+    amount_in: u64,
+    amount_per_period: u64,
+    period: u64,
+    max_twap_deviation_bps: u64,
+) -> Result<()> {
+    require_gt!(amount_per_period, 0);
+    require_gt!(period, 0);
+    check_max_twap_deviation_bps(
+        max_twap_deviation_bps,
+        ctx.accounts.amm_config.trade_fee_rate,
+    )?;
+    let transfer_fee =
+        get_transfer_fee(&ctx.accounts.input_token_mint.to_account_info(), amount_in)?;
+    let received_amount = amount_in.saturating_sub(transfer_fee);
+    require_gt!(received_amount, 0);
+
+    transfer_from_user_to_pool_vault(
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.owner_input_token_account.to_account_info(),
+        ctx.accounts.input_token_vault.to_account_info(),
+        ctx.accounts.input_token_mint.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        amount_in,
+        ctx.accounts.input_token_mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+
+    let dca_vault = ctx.accounts.dca_vault.as_mut();
+    dca_vault.bump = ctx.bumps.dca_vault;
+    dca_vault.owner = ctx.accounts.owner.key();
+    dca_vault.pool_id = ctx.accounts.pool_state.key();
+    dca_vault.input_mint = ctx.accounts.input_token_mint.key();
+    dca_vault.output_mint = ctx.accounts.output_token_mint.key();
+    dca_vault.nonce = nonce;
+    dca_vault.amount_per_period = amount_per_period;
+    dca_vault.period = period;
+    dca_vault.max_twap_deviation_bps = max_twap_deviation_bps;
+    dca_vault.input_amount = received_amount;
+    dca_vault.next_execution_time = Clock::get()?.unix_timestamp as u64;
+
+    emit!(DcaVaultEvent {
+        dca_vault: dca_vault.key(),
+        owner: dca_vault.owner,
+        pool_id: dca_vault.pool_id,
+        input_mint: dca_vault.input_mint,
+        output_mint: dca_vault.output_mint,
+        amount_in: received_amount,
+        amount_out: 0,
+        input_amount: dca_vault.input_amount,
+        output_amount: 0,
+        change_type: 0,
+    });
+    Ok(())
+}
@@ -0,0 +1,152 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct PlaceLimitOrder<'info> {
+    /// The owner of the order
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: pool vault and order vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The pool the order is filled against
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The mint of the escrowed token
+    #[account(
+        mint::token_program = input_token_program,
+        constraint = input_token_mint.key() == pool_state.load()?.token_0_mint || input_token_mint.key() == pool_state.load()?.token_1_mint @ ErrorCode::InvalidInput
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The order
+    #[account(
+        init,
+        seeds = [
+            LIMIT_ORDER_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            owner.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+        payer = owner,
+        space = LimitOrder::LEN
+    )]
+    pub limit_order: Box<Account<'info, LimitOrder>>,
+
+    /// The vault escrowing the input tokens of all orders
+    #[account(
+        init_if_needed,
+        seeds = [
+            LIMIT_ORDER_VAULT_SEED.as_bytes(),
+            input_token_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = input_token_mint,
+        token::authority = authority,
+        token::token_program = input_token_program,
+    )]
+    pub order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account paying the input token
+    #[account(
+        mut,
+        token::mint = input_token_mint,
+        token::authority = owner,
+    )]
+    pub owner_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022 of the input token
+    pub input_token_program: Interface<'info, TokenInterface>,
+    /// To create a new program account
+    pub system_program: Program<'info, System>,
+}
+
+pub fn place_limit_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceLimitOrder<'info>>,
+    nonce: u64,
+    amount_in: u64,
+    target_price_x32: u128,
+    minimum_amount_out: u64,
+    keeper_bounty: u64,
+) -> Result<()> {
+    require_gt!(target_price_x32, 0);
+    let transfer_fee =
+        get_transfer_fee(&ctx.accounts.input_token_mint.to_account_info(), amount_in)?;
+    let received_amount = amount_in.saturating_sub(transfer_fee);
+    require_gt!(received_amount, 0);
+
+    transfer_from_user_to_pool_vault(
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.owner_input_token_account.to_account_info(),
+        ctx.accounts.order_vault.to_account_info(),
+        ctx.accounts.input_token_mint.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        amount_in,
+        ctx.accounts.input_token_mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+
+    // the bounty is held by the order account until it is filled or cancelled
+    if keeper_bounty > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: ctx.accounts.limit_order.to_account_info(),
+                },
+            ),
+            keeper_bounty,
+        )?;
+    }
+
+    let output_mint = {
+        let pool_state = ctx.accounts.pool_state.load()?;
+        if ctx.accounts.input_token_mint.key() == pool_state.token_0_mint {
+            pool_state.token_1_mint
+        } else {
+            pool_state.token_0_mint
+        }
+    };
+
+    let limit_order = ctx.accounts.limit_order.as_mut();
+    limit_order.bump = ctx.bumps.limit_order;
+    limit_order.owner = ctx.accounts.owner.key();
+    limit_order.pool_id = ctx.accounts.pool_state.key();
+    limit_order.input_mint = ctx.accounts.input_token_mint.key();
+    limit_order.output_mint = output_mint;
+    limit_order.nonce = nonce;
+    limit_order.amount_in = received_amount;
+    limit_order.minimum_amount_out = minimum_amount_out;
+    limit_order.target_price_x32 = target_price_x32;
+    limit_order.keeper_bounty = keeper_bounty;
+    limit_order.open_time = Clock::get()?.unix_timestamp as u64;
+
+    emit!(LimitOrderEvent {
+        order_id: limit_order.key(),
+        owner: limit_order.owner,
+        pool_id: limit_order.pool_id,
+        input_mint: limit_order.input_mint,
+        output_mint: limit_order.output_mint,
+        amount_in: limit_order.amount_in,
+        amount_out: 0,
+        target_price_x32,
+        keeper_bounty,
+        change_type: 0,
+    });
+    Ok(())
+}
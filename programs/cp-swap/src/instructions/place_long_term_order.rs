@@ -0,0 +1,167 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::swap::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct PlaceLongTermOrder<'info> {
+    /// The owner of the order
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: pool vault and order vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The pool the order is executed against
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The factory state to read the trade fee rate
+    #[account(address = pool_state.load()?.amm_config)]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The mint of the escrowed token
+    #[account(
+        mint::token_program = input_token_program,
+        constraint = input_token_mint.key() == pool_state.load()?.token_0_mint || input_token_mint.key() == pool_state.load()?.token_1_mint @ ErrorCode::InvalidInput
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of the token bought
+    #[account(
+        mint::token_program = output_token_program,
+        constraint = output_token_mint.key() == pool_state.load()?.token_0_mint || output_token_mint.key() == pool_state.load()?.token_1_mint @ ErrorCode::InvalidInput,
+        constraint = output_token_mint.key() != input_token_mint.key() @ ErrorCode::InvalidInput
+    )]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The order
+    #[account(
+        init,
+        seeds = [
+            LONG_TERM_ORDER_SEED.as_bytes(),
+            pool_state.key().as_ref(),
+            owner.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump,
+        payer = owner,
+        space = LongTermOrder::LEN
+    )]
+    pub long_term_order: Box<Account<'info, LongTermOrder>>,
+
+    /// The vault escrowing the input tokens of all orders
+    #[account(
+        init_if_needed,
+        seeds = [
+            LONG_TERM_ORDER_VAULT_SEED.as_bytes(),
+            input_token_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = input_token_mint,
+        token::authority = authority,
+        token::token_program = input_token_program,
+    )]
+    pub input_order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault holding the bought tokens of all orders
+    #[account(
+        init_if_needed,
+        seeds = [
+            LONG_TERM_ORDER_VAULT_SEED.as_bytes(),
+            output_token_mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = output_token_mint,
+        token::authority = authority,
+        token::token_program = output_token_program,
+    )]
+    pub output_order_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account paying the input token
+    #[account(
+        mut,
+        token::mint = input_token_mint,
+        token::authority = owner,
+    )]
+    pub owner_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022 of the input token
+    pub input_token_program: Interface<'info, TokenInterface>,
+    /// Spl token program or token program 2022 of the output token
+    pub output_token_program: Interface<'info, TokenInterface>,
+    /// To create a new program account
+    pub system_program: Program<'info, System>,
+}
+
+pub fn place_long_term_order<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceLongTermOrder<'info>>,
+    nonce: u64,
+    amount_in: u64,
+    duration: u64,
+    max_amount_per_execution: u64,
+    max_twap_deviation_bps: u64,
+) -> Result<()> {
+    require_gt!(duration, 0);
+    require_gt!(max_amount_per_execution, 0);
+    check_max_twap_deviation_bps(
+        max_twap_deviation_bps,
+        ctx.accounts.amm_config.trade_fee_rate,
+    )?;
+    let transfer_fee =
+        get_transfer_fee(&ctx.accounts.input_token_mint.to_account_info(), amount_in)?;
+    let received_amount = amount_in.saturating_sub(transfer_fee);
+    require_gt!(received_amount, 0);
+
+    transfer_from_user_to_pool_vault(
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.owner_input_token_account.to_account_info(),
+        ctx.accounts.input_order_vault.to_account_info(),
+        ctx.accounts.input_token_mint.to_account_info(),
+        ctx.accounts.input_token_program.to_account_info(),
+        amount_in,
+        ctx.accounts.input_token_mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+
+    let block_timestamp = Clock::get()?.unix_timestamp as u64;
+    let long_term_order = ctx.accounts.long_term_order.as_mut();
+    long_term_order.bump = ctx.bumps.long_term_order;
+    long_term_order.owner = ctx.accounts.owner.key();
+    long_term_order.pool_id = ctx.accounts.pool_state.key();
+    long_term_order.input_mint = ctx.accounts.input_token_mint.key();
+    long_term_order.output_mint = ctx.accounts.output_token_mint.key();
+    long_term_order.nonce = nonce;
+    long_term_order.deposit_amount = received_amount;
+    long_term_order.start_time = block_timestamp;
+    long_term_order.end_time = block_timestamp
+        .checked_add(duration)
+        .ok_or(ErrorCode::MathOverflow)?;
+    long_term_order.last_execution_time = block_timestamp;
+    long_term_order.max_amount_per_execution = max_amount_per_execution;
+    long_term_order.max_twap_deviation_bps = max_twap_deviation_bps;
+
+    emit!(LongTermOrderEvent {
+        order_id: long_term_order.key(),
+        owner: long_term_order.owner,
+        pool_id: long_term_order.pool_id,
+        input_mint: long_term_order.input_mint,
+        output_mint: long_term_order.output_mint,
+        amount_in: received_amount,
+        amount_out: 0,
+        sold_amount: 0,
+        bought_amount: 0,
+        change_type: 0,
+    });
+    Ok(())
+}
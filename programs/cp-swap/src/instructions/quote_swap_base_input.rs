@@ -0,0 +1,192 @@
+use crate::curve::calculator::CurveCalculator;
+use crate::curve::fees::Fees;
+use crate::curve::TradeDirection;
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::swap::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+#[derive(Accounts)]
+pub struct QuoteSwap<'info> {
+    /// The factory state to read fee rates
+    #[account(address = pool_state.load()?.amm_config)]
+    pub amm_config: Box<Account<'info, AmmConfig>>,
+
+    /// The program account of the pool to quote against
+    pub pool_state: AccountLoader<'info, PoolState>,
+
+    /// The vault token account for input token
+    #[account(
+        constraint = input_vault.key() == pool_state.load()?.token_0_vault || input_vault.key() == pool_state.load()?.token_1_vault
+    )]
+    pub input_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The vault token account for output token
+    #[account(
+        constraint = output_vault.key() == pool_state.load()?.token_0_vault || output_vault.key() == pool_state.load()?.token_1_vault
+    )]
+    pub output_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of input token
+    #[account(
+        address = input_vault.mint
+    )]
+    pub input_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of output token
+    #[account(
+        address = output_vault.mint
+    )]
+    pub output_token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The program account for the most recent oracle observation, read by the circuit breaker
+    #[account(address = pool_state.load()?.observation_key)]
+    pub observation_state: AccountLoader<'info, ObservationState>,
+
+    /// The K token stake of the trader, its staking tier discounts the trade fee
+    #[account(
+        constraint = user_stake.mint == amm_config.fee_token_mint @ ErrorCode::InvalidFeeToken
+    )]
+    pub user_stake: Option<Box<Account<'info, UserStake>>>,
+
+    /// Protocol state holding the protocol-wide status
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    /// The referrer token account for input token, receives a share of the protocol fee
+    #[account(
+        token::mint = input_token_mint,
+    )]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> QuoteSwap<'info> {
+    /// The trade fee rate of the trader after the staking tier discount
+    pub fn trade_fee_rate(&self) -> u64 {
+        match &self.user_stake {
+            Some(user_stake) => self.amm_config.get_staker_trade_fee_rate(user_stake.amount),
+            None => self.amm_config.trade_fee_rate,
+        }
+    }
+
+    /// The share of the protocol fee paid to the referrer, 0 without a referrer
+    pub fn referral_fee(&self, protocol_fee: u128) -> Result<u64> {
+        match &self.referrer_token_account {
+            Some(_) => Fees::referral_fee(protocol_fee, self.amm_config.referral_fee_rate)
+                .and_then(|referral_fee| u64::try_from(referral_fee).ok())
+                .ok_or(ErrorCode::MathOverflow.into()),
+            None => Ok(0),
+        }
+    }
+}
+
+impl<'info> QuoteSwap<'info> {
+    /// Runs the circuit breaker of the pool on a copy of the pool state, so the quote fails
+    /// where the swap is rejected and the pool account is left unchanged
+    pub fn check_circuit_breaker(
+        &self,
+        pool_state: &PoolState,
+        trade_direction: TradeDirection,
+        (token_0_price_x64, token_1_price_x64): (u128, u128),
+        new_vault_amounts: (u128, u128),
+    ) -> Result<()> {
+        let block_timestamp = Clock::get()?.unix_timestamp as u64;
+        let swap_prices = SwapPrices::new(
+            pool_state,
+            trade_direction,
+            (token_0_price_x64, token_1_price_x64),
+            &self.input_token_mint.to_account_info(),
+            &self.output_token_mint.to_account_info(),
+            block_timestamp,
+        )?;
+        let mut pool_state = *pool_state;
+        pool_state.check_circuit_breaker(
+            &*self.observation_state.load()?,
+            block_timestamp,
+            swap_prices.pre_swap_price_x32,
+            (
+                swap_prices.token_0_ui_multiplier_x32,
+                swap_prices.token_1_ui_multiplier_x32,
+            ),
+            trade_direction,
+            new_vault_amounts,
+        )?;
+        Ok(())
+    }
+}
+
+/// Quotes the swap base input with the same checks, fee rates and return data as `swap_base_input`
+pub fn quote_swap_base_input(
+    ctx: Context<QuoteSwap>,
+    amount_in: u64,
+    price_limit_x32: u128,
+) -> Result<()> {
+    let block_timestamp = Clock::get()?.unix_timestamp as u64;
+    let pool_state = ctx.accounts.pool_state.load()?;
+    check_swap_status(&pool_state, &ctx.accounts.protocol_state, block_timestamp)?;
+
+    let input_transfer_fee =
+        get_transfer_fee(&ctx.accounts.input_token_mint.to_account_info(), amount_in)?;
+    let actual_amount_in = amount_in.saturating_sub(input_transfer_fee);
+    require_gt!(actual_amount_in, 0);
+
+    let SwapParams {
+        trade_direction,
+        total_input_token_amount,
+        total_output_token_amount,
+        token_0_price_x64,
+        token_1_price_x64,
+        is_creator_fee_on_input,
+    } = pool_state.get_swap_params(
+        ctx.accounts.input_vault.key(),
+        ctx.accounts.output_vault.key(),
+        ctx.accounts.input_vault.amount,
+        ctx.accounts.output_vault.amount,
+    )?;
+
+    let creator_fee_rate =
+        pool_state.adjust_creator_fee_rate(ctx.accounts.amm_config.creator_fee_rate);
+    let result = CurveCalculator::swap_base_input(
+        u128::from(actual_amount_in),
+        u128::from(total_input_token_amount),
+        u128::from(total_output_token_amount),
+        ctx.accounts.trade_fee_rate(),
+        creator_fee_rate,
+        ctx.accounts.amm_config.protocol_fee_rate,
+        ctx.accounts.amm_config.fund_fee_rate,
+        is_creator_fee_on_input,
+    )
+    .ok_or(ErrorCode::ZeroTradingTokens)?;
+    require!(
+        price_limit_x32 == 0 || result.post_swap_input_price_x32() >= price_limit_x32,
+        ErrorCode::PriceLimitExceeded
+    );
+    ctx.accounts.check_circuit_breaker(
+        &pool_state,
+        trade_direction,
+        (token_0_price_x64, token_1_price_x64),
+        (
+            result.new_input_vault_amount,
+            result.new_output_vault_amount,
+        ),
+    )?;
+
+    let output_transfer_fee = get_transfer_fee(
+        &ctx.accounts.output_token_mint.to_account_info(),
+        u64::try_from(result.output_amount).unwrap(),
+    )?;
+    let referral_fee = ctx.accounts.referral_fee(result.protocol_fee)?;
+
+    SwapReturnData::new(
+        &result,
+        input_transfer_fee,
+        output_transfer_fee,
+        referral_fee,
+    )
+    .set()
+}
@@ -0,0 +1,85 @@
+use super::quote_swap_base_input::QuoteSwap;
+use crate::curve::calculator::CurveCalculator;
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::swap::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+
+/// Quotes the swap base output with the same checks, fee rates and return data as `swap_base_output`
+pub fn quote_swap_base_output(
+    ctx: Context<QuoteSwap>,
+    amount_out_received: u64,
+    price_limit_x32: u128,
+) -> Result<()> {
+    require_gt!(amount_out_received, 0);
+    let block_timestamp = Clock::get()?.unix_timestamp as u64;
+    let pool_state = ctx.accounts.pool_state.load()?;
+    check_swap_status(&pool_state, &ctx.accounts.protocol_state, block_timestamp)?;
+
+    let output_transfer_fee = get_transfer_inverse_fee(
+        &ctx.accounts.output_token_mint.to_account_info(),
+        amount_out_received,
+    )?;
+    let amount_out_with_transfer_fee = amount_out_received
+        .checked_add(output_transfer_fee)
+        .unwrap();
+
+    let SwapParams {
+        trade_direction,
+        total_input_token_amount,
+        total_output_token_amount,
+        token_0_price_x64,
+        token_1_price_x64,
+        is_creator_fee_on_input,
+    } = pool_state.get_swap_params(
+        ctx.accounts.input_vault.key(),
+        ctx.accounts.output_vault.key(),
+        ctx.accounts.input_vault.amount,
+        ctx.accounts.output_vault.amount,
+    )?;
+
+    let creator_fee_rate =
+        pool_state.adjust_creator_fee_rate(ctx.accounts.amm_config.creator_fee_rate);
+    let result = CurveCalculator::swap_base_output(
+        u128::from(amount_out_with_transfer_fee),
+        u128::from(total_input_token_amount),
+        u128::from(total_output_token_amount),
+        ctx.accounts.trade_fee_rate(),
+        creator_fee_rate,
+        ctx.accounts.amm_config.protocol_fee_rate,
+        ctx.accounts.amm_config.fund_fee_rate,
+        is_creator_fee_on_input,
+    )
+    .ok_or(ErrorCode::ZeroTradingTokens)?;
+    require!(
+        price_limit_x32 == 0 || result.post_swap_input_price_x32() >= price_limit_x32,
+        ErrorCode::PriceLimitExceeded
+    );
+    ctx.accounts.check_circuit_breaker(
+        &pool_state,
+        trade_direction,
+        (token_0_price_x64, token_1_price_x64),
+        (
+            result.new_input_vault_amount,
+            result.new_output_vault_amount,
+        ),
+    )?;
+
+    let input_amount = u64::try_from(result.input_amount).unwrap();
+    require_gt!(input_amount, 0);
+    let input_transfer_fee = get_transfer_inverse_fee(
+        &ctx.accounts.input_token_mint.to_account_info(),
+        input_amount,
+    )?;
+
+    let referral_fee = ctx.accounts.referral_fee(result.protocol_fee)?;
+
+    SwapReturnData::new(
+        &result,
+        input_transfer_fee,
+        output_transfer_fee,
+        referral_fee,
+    )
+    .set()
+}
@@ -0,0 +1,54 @@
+use super::quote_deposit::QuoteLpChange;
+use crate::curve::CurveCalculator;
+use crate::curve::RoundDirection;
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+
+pub fn quote_withdraw(ctx: Context<QuoteLpChange>, lp_token_amount: u64) -> Result<()> {
+    require_gt!(lp_token_amount, 0);
+    let pool_state = ctx.accounts.pool_state.load()?;
+    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Withdraw)
+        || !ctx
+            .accounts
+            .protocol_state
+            .get_status_by_bit(PoolStatusBitIndex::Withdraw)
+    {
+        return err!(ErrorCode::NotApproved);
+    }
+    let (total_token_0_amount, total_token_1_amount) = pool_state.vault_amount_without_fee(
+        ctx.accounts.token_0_vault.amount,
+        ctx.accounts.token_1_vault.amount,
+    )?;
+    let results = CurveCalculator::lp_tokens_to_trading_tokens(
+        u128::from(lp_token_amount),
+        u128::from(pool_state.lp_supply),
+        u128::from(total_token_0_amount),
+        u128::from(total_token_1_amount),
+        RoundDirection::Floor,
+    )
+    .ok_or(ErrorCode::ZeroTradingTokens)?;
+    if results.token_0_amount == 0 || results.token_1_amount == 0 {
+        return err!(ErrorCode::ZeroTradingTokens);
+    }
+    let token_0_amount = u64::try_from(results.token_0_amount).unwrap();
+    let token_0_amount = std::cmp::min(total_token_0_amount, token_0_amount);
+    let token_0_transfer_fee =
+        get_transfer_fee(&ctx.accounts.vault_0_mint.to_account_info(), token_0_amount)?;
+    let token_1_amount = u64::try_from(results.token_1_amount).unwrap();
+    let token_1_amount = std::cmp::min(total_token_1_amount, token_1_amount);
+    let token_1_transfer_fee =
+        get_transfer_fee(&ctx.accounts.vault_1_mint.to_account_info(), token_1_amount)?;
+
+    LpChangeReturnData {
+        lp_token_amount,
+        token_0_amount,
+        token_1_amount,
+        token_0_transfer_fee,
+        token_1_transfer_fee,
+        new_token_0_vault_amount: total_token_0_amount - token_0_amount,
+        new_token_1_vault_amount: total_token_1_amount - token_1_amount,
+    }
+    .set()
+}
@@ -0,0 +1,30 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetCreatorFeeRecipient<'info> {
+    /// Only pool creator can set the recipient
+    #[account(address = pool_state.load()?.pool_creator @ ErrorCode::InvalidOwner)]
+    pub creator: Signer<'info>,
+
+    /// The pool whose creator fee recipient is set
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+pub fn set_creator_fee_recipient(
+    ctx: Context<SetCreatorFeeRecipient>,
+    recipient: Pubkey,
+) -> Result<()> {
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    let old_recipient = pool_state.get_creator_fee_recipient();
+    pool_state.creator_fee_recipient = recipient;
+
+    emit!(CreatorFeeRecipientUpdatedEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        old_recipient,
+        new_recipient: pool_state.get_creator_fee_recipient(),
+    });
+    Ok(())
+}
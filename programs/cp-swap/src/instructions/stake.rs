@@ -0,0 +1,105 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    /// The user staking the K token
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: pool vault, lp mint and stake vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The K token mint
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The stake of the user
+    #[account(
+        init_if_needed,
+        seeds = [
+            USER_STAKE_SEED.as_bytes(),
+            mint.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        space = UserStake::LEN
+    )]
+    pub user_stake: Box<Account<'info, UserStake>>,
+
+    /// The vault holding the staked K tokens
+    #[account(
+        init_if_needed,
+        seeds = [
+            STAKE_VAULT_SEED.as_bytes(),
+            mint.key().as_ref(),
+        ],
+        bump,
+        payer = owner,
+        token::mint = mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account paying the K token
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022
+    pub token_program: Interface<'info, TokenInterface>,
+    /// To create a new program account
+    pub system_program: Program<'info, System>,
+}
+
+pub fn stake<'info>(ctx: Context<'_, '_, '_, 'info, Stake<'info>>, amount: u64) -> Result<()> {
+    let transfer_fee = get_transfer_fee(&ctx.accounts.mint.to_account_info(), amount)?;
+    let received_amount = amount.saturating_sub(transfer_fee);
+    require_gt!(received_amount, 0);
+
+    transfer_from_user_to_pool_vault(
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.stake_vault.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        ctx.accounts.mint.decimals,
+        ctx.remaining_accounts,
+    )?;
+
+    let block_timestamp = Clock::get()?.unix_timestamp as u64;
+    let user_stake = ctx.accounts.user_stake.as_mut();
+    user_stake.bump = ctx.bumps.user_stake;
+    user_stake.owner = ctx.accounts.owner.key();
+    user_stake.mint = ctx.accounts.mint.key();
+    user_stake.amount = user_stake
+        .amount
+        .checked_add(received_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    user_stake.unlock_time = block_timestamp + STAKE_LOCK_DURATION;
+
+    emit!(StakeEvent {
+        owner: user_stake.owner,
+        mint: user_stake.mint,
+        amount: received_amount,
+        staked_amount: user_stake.amount,
+        unlock_time: user_stake.unlock_time,
+        change_type: 0,
+    });
+    Ok(())
+}
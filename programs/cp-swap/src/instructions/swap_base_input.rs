@@ -1,6 +1,8 @@
 use crate::curve::calculator::CurveCalculator;
+use crate::curve::fees::Fees;
 use crate::error::ErrorCode;
 use crate::states::*;
+use crate::utils::swap::*;
 use crate::utils::token::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
@@ -70,17 +72,93 @@ pub struct Swap<'info> {
     /// The program account for the most recent oracle observation
     #[account(mut, address = pool_state.load()?.observation_key)]
     pub observation_state: AccountLoader<'info, ObservationState>,
+
+    /// Protocol state holding the protocol-wide status
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
+
+    /// The K token stake of the payer, its staking tier discounts the trade fee
+    #[account(
+        seeds = [
+            USER_STAKE_SEED.as_bytes(),
+            amm_config.fee_token_mint.as_ref(),
+            payer.key().as_ref(),
+        ],
+        bump = user_stake.bump,
+    )]
+    pub user_stake: Option<Box<Account<'info, UserStake>>>,
+
+    /// The referrer token account for input token, receives a share of the protocol fee.
+    /// It cannot be owned by the payer, this does not stop a payer referring itself from another wallet
+    #[account(
+        mut,
+        token::mint = input_token_mint,
+        constraint = referrer_token_account.owner != payer.key() @ ErrorCode::InvalidReferrer
+    )]
+    pub referrer_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+}
+
+impl<'info> Swap<'info> {
+    /// The trade fee rate of the payer after the staking tier discount
+    pub fn trade_fee_rate(&self) -> u64 {
+        match &self.user_stake {
+            Some(user_stake) => self.amm_config.get_staker_trade_fee_rate(user_stake.amount),
+            None => self.amm_config.trade_fee_rate,
+        }
+    }
+
+    /// The share of the protocol fee paid to the referrer, 0 without a referrer
+    pub fn referral_fee(&self, protocol_fee: u128) -> Result<u64> {
+        match &self.referrer_token_account {
+            Some(_) => Fees::referral_fee(protocol_fee, self.amm_config.referral_fee_rate)
+                .and_then(|referral_fee| u64::try_from(referral_fee).ok())
+                .ok_or(ErrorCode::MathOverflow.into()),
+            None => Ok(0),
+        }
+    }
+
+    /// Pay the referral fee from the input vault to the referrer
+    pub fn pay_referral_fee(
+        &self,
+        referral_fee: u64,
+        auth_bump: u8,
+        hook_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        match &self.referrer_token_account {
+            Some(referrer_token_account) if referral_fee > 0 => transfer_from_pool_vault_to_user(
+                self.authority.to_account_info(),
+                self.input_vault.to_account_info(),
+                referrer_token_account.to_account_info(),
+                self.input_token_mint.to_account_info(),
+                self.input_token_program.to_account_info(),
+                referral_fee,
+                self.input_token_mint.decimals,
+                &[&[crate::AUTH_SEED.as_bytes(), &[auth_bump]]],
+                hook_accounts,
+            ),
+            _ => Ok(()),
+        }
+    }
 }
 
-pub fn swap_base_input(ctx: Context<Swap>, amount_in: u64, minimum_amount_out: u64) -> Result<()> {
+pub fn swap_base_input<'info>(
+    ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+    deadline: u64,
+    price_limit_x32: u128,
+) -> Result<()> {
     let block_timestamp = solana_program::clock::Clock::get()?.unix_timestamp as u64;
+    require!(
+        deadline == 0 || block_timestamp <= deadline,
+        ErrorCode::DeadlineExceeded
+    );
     let pool_id = ctx.accounts.pool_state.key();
     let pool_state = &mut ctx.accounts.pool_state.load_mut()?;
-    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Swap)
-        || block_timestamp < pool_state.open_time
-    {
-        return err!(ErrorCode::NotApproved);
-    }
+    check_swap_status(pool_state, &ctx.accounts.protocol_state, block_timestamp)?;
 
     let transfer_fee =
         get_transfer_fee(&ctx.accounts.input_token_mint.to_account_info(), amount_in)?;
@@ -111,13 +189,17 @@ pub fn swap_base_input(ctx: Context<Swap>, amount_in: u64, minimum_amount_out: u
         u128::from(actual_amount_in),
         u128::from(total_input_token_amount),
         u128::from(total_output_token_amount),
-        ctx.accounts.amm_config.trade_fee_rate,
+        ctx.accounts.trade_fee_rate(),
         creator_fee_rate,
         ctx.accounts.amm_config.protocol_fee_rate,
         ctx.accounts.amm_config.fund_fee_rate,
         is_creator_fee_on_input,
     )
     .ok_or(ErrorCode::ZeroTradingTokens)?;
+    require!(
+        price_limit_x32 == 0 || result.post_swap_input_price_x32() >= price_limit_x32,
+        ErrorCode::PriceLimitExceeded
+    );
 
     let constant_after = u128::from(result.new_input_vault_amount)
         .checked_mul(u128::from(result.new_output_vault_amount))
@@ -155,8 +237,29 @@ pub fn swap_base_input(ctx: Context<Swap>, amount_in: u64, minimum_amount_out: u
         (amount_out, transfer_fee)
     };
 
+    let swap_prices = SwapPrices::new(
+        pool_state,
+        trade_direction,
+        (token_0_price_x64, token_1_price_x64),
+        &ctx.accounts.input_token_mint.to_account_info(),
+        &ctx.accounts.output_token_mint.to_account_info(),
+        block_timestamp,
+    )?;
+    swap_prices.check_circuit_breaker(
+        pool_state,
+        pool_id,
+        &*ctx.accounts.observation_state.load()?,
+        block_timestamp,
+        trade_direction,
+        (
+            result.new_input_vault_amount,
+            result.new_output_vault_amount,
+        ),
+    )?;
+
+    let referral_fee = ctx.accounts.referral_fee(result.protocol_fee)?;
     pool_state.update_fees(
-        u64::try_from(result.protocol_fee).unwrap(),
+        u64::try_from(result.protocol_fee).unwrap() - referral_fee,
         u64::try_from(result.fund_fee).unwrap(),
         u64::try_from(result.creator_fee).unwrap(),
         trade_direction,
@@ -176,6 +279,15 @@ pub fn swap_base_input(ctx: Context<Swap>, amount_in: u64, minimum_amount_out: u
         trade_fee: u64::try_from(result.trade_fee).unwrap(),
         creator_fee: u64::try_from(result.creator_fee).unwrap(),
         creator_fee_on_input: is_creator_fee_on_input,
+        input_ui_amount: to_ui_amount(
+            u64::try_from(result.input_amount).unwrap(),
+            swap_prices.input_ui_multiplier_x32,
+        ),
+        output_ui_amount: to_ui_amount(
+            u64::try_from(result.output_amount).unwrap(),
+            swap_prices.output_ui_multiplier_x32,
+        ),
+        referral_fee,
     });
     require_gte!(constant_after, constant_before);
 
@@ -187,8 +299,12 @@ pub fn swap_base_input(ctx: Context<Swap>, amount_in: u64, minimum_amount_out: u
         ctx.accounts.input_token_program.to_account_info(),
         input_transfer_amount,
         ctx.accounts.input_token_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
+    ctx.accounts
+        .pay_referral_fee(referral_fee, pool_state.auth_bump, ctx.remaining_accounts)?;
+
     transfer_from_pool_vault_to_user(
         ctx.accounts.authority.to_account_info(),
         ctx.accounts.output_vault.to_account_info(),
@@ -198,15 +314,20 @@ pub fn swap_base_input(ctx: Context<Swap>, amount_in: u64, minimum_amount_out: u
         output_transfer_amount,
         ctx.accounts.output_token_mint.decimals,
         &[&[crate::AUTH_SEED.as_bytes(), &[pool_state.auth_bump]]],
+        ctx.remaining_accounts,
     )?;
 
-    // update the previous price to the observation
-    ctx.accounts.observation_state.load_mut()?.update(
-        oracle::block_timestamp(),
-        token_0_price_x64,
-        token_1_price_x64,
-    );
-    pool_state.recent_epoch = Clock::get()?.epoch;
+    swap_prices.update_observation(
+        pool_state,
+        &mut *ctx.accounts.observation_state.load_mut()?,
+        block_timestamp,
+    )?;
 
-    Ok(())
+    SwapReturnData::new(
+        &result,
+        input_transfer_fee,
+        output_transfer_fee,
+        referral_fee,
+    )
+    .set()
 }
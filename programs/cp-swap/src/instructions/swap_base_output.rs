@@ -2,24 +2,27 @@ use super::swap_base_input::Swap;
 use crate::curve::calculator::CurveCalculator;
 use crate::error::ErrorCode;
 use crate::states::*;
+use crate::utils::swap::*;
 use crate::utils::token::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
 
-pub fn swap_base_output(
-    ctx: Context<Swap>,
+pub fn swap_base_output<'info>(
+    ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
     max_amount_in: u64,
     amount_out_received: u64,
+    deadline: u64,
+    price_limit_x32: u128,
 ) -> Result<()> {
     require_gt!(amount_out_received, 0);
     let block_timestamp = solana_program::clock::Clock::get()?.unix_timestamp as u64;
+    require!(
+        deadline == 0 || block_timestamp <= deadline,
+        ErrorCode::DeadlineExceeded
+    );
     let pool_id = ctx.accounts.pool_state.key();
     let pool_state = &mut ctx.accounts.pool_state.load_mut()?;
-    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Swap)
-        || block_timestamp < pool_state.open_time
-    {
-        return err!(ErrorCode::NotApproved);
-    }
+    check_swap_status(pool_state, &ctx.accounts.protocol_state, block_timestamp)?;
     let out_transfer_fee = get_transfer_inverse_fee(
         &ctx.accounts.output_token_mint.to_account_info(),
         amount_out_received,
@@ -49,13 +52,17 @@ pub fn swap_base_output(
         u128::from(amount_out_with_transfer_fee),
         u128::from(total_input_token_amount),
         u128::from(total_output_token_amount),
-        ctx.accounts.amm_config.trade_fee_rate,
+        ctx.accounts.trade_fee_rate(),
         creator_fee_rate,
         ctx.accounts.amm_config.protocol_fee_rate,
         ctx.accounts.amm_config.fund_fee_rate,
         is_creator_fee_on_input,
     )
     .ok_or(ErrorCode::ZeroTradingTokens)?;
+    require!(
+        price_limit_x32 == 0 || result.post_swap_input_price_x32() >= price_limit_x32,
+        ErrorCode::PriceLimitExceeded
+    );
 
     let constant_after = u128::from(result.new_input_vault_amount)
         .checked_mul(u128::from(result.new_output_vault_amount))
@@ -97,8 +104,29 @@ pub fn swap_base_output(
     let (output_transfer_amount, output_transfer_fee) =
         (amount_out_with_transfer_fee, out_transfer_fee);
 
+    let swap_prices = SwapPrices::new(
+        pool_state,
+        trade_direction,
+        (token_0_price_x64, token_1_price_x64),
+        &ctx.accounts.input_token_mint.to_account_info(),
+        &ctx.accounts.output_token_mint.to_account_info(),
+        block_timestamp,
+    )?;
+    swap_prices.check_circuit_breaker(
+        pool_state,
+        pool_id,
+        &*ctx.accounts.observation_state.load()?,
+        block_timestamp,
+        trade_direction,
+        (
+            result.new_input_vault_amount,
+            result.new_output_vault_amount,
+        ),
+    )?;
+
+    let referral_fee = ctx.accounts.referral_fee(result.protocol_fee)?;
     pool_state.update_fees(
-        u64::try_from(result.protocol_fee).unwrap(),
+        u64::try_from(result.protocol_fee).unwrap() - referral_fee,
         u64::try_from(result.fund_fee).unwrap(),
         u64::try_from(result.creator_fee).unwrap(),
         trade_direction,
@@ -118,6 +146,15 @@ pub fn swap_base_output(
         trade_fee: u64::try_from(result.trade_fee).unwrap(),
         creator_fee: u64::try_from(result.creator_fee).unwrap(),
         creator_fee_on_input: is_creator_fee_on_input,
+        input_ui_amount: to_ui_amount(
+            u64::try_from(result.input_amount).unwrap(),
+            swap_prices.input_ui_multiplier_x32,
+        ),
+        output_ui_amount: to_ui_amount(
+            u64::try_from(result.output_amount).unwrap(),
+            swap_prices.output_ui_multiplier_x32,
+        ),
+        referral_fee,
     });
     require_gte!(constant_after, constant_before);
 
@@ -129,8 +166,12 @@ pub fn swap_base_output(
         ctx.accounts.input_token_program.to_account_info(),
         input_transfer_amount,
         ctx.accounts.input_token_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
+    ctx.accounts
+        .pay_referral_fee(referral_fee, pool_state.auth_bump, ctx.remaining_accounts)?;
+
     transfer_from_pool_vault_to_user(
         ctx.accounts.authority.to_account_info(),
         ctx.accounts.output_vault.to_account_info(),
@@ -140,15 +181,20 @@ pub fn swap_base_output(
         output_transfer_amount,
         ctx.accounts.output_token_mint.decimals,
         &[&[crate::AUTH_SEED.as_bytes(), &[pool_state.auth_bump]]],
+        ctx.remaining_accounts,
     )?;
 
-    // update the previous price to the observation
-    ctx.accounts.observation_state.load_mut()?.update(
-        oracle::block_timestamp(),
-        token_0_price_x64,
-        token_1_price_x64,
-    );
-    pool_state.recent_epoch = Clock::get()?.epoch;
+    swap_prices.update_observation(
+        pool_state,
+        &mut *ctx.accounts.observation_state.load_mut()?,
+        block_timestamp,
+    )?;
 
-    Ok(())
+    SwapReturnData::new(
+        &result,
+        input_transfer_fee,
+        output_transfer_fee,
+        referral_fee,
+    )
+    .set()
 }
@@ -1,6 +1,7 @@
 use crate::curve::calculator::CurveCalculator;
 use crate::error::ErrorCode;
 use crate::states::*;
+use crate::utils::swap::*;
 use crate::utils::token::*;
 use crate::utils::PythOracle;
 use anchor_lang::prelude::*;
@@ -99,10 +100,20 @@ pub struct SwapWithKToken<'info> {
 
     /// SPL program for K token transfers
     pub k_token_program: Interface<'info, TokenInterface>,
+
+    /// Protocol state holding the protocol-wide status
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
 }
 
-pub fn swap_with_k_token(
-    ctx: Context<SwapWithKToken>,
+/// The K token fee payment is the discount of this swap, the staking tier discount of the payer
+/// does not apply and the swap is priced at the trade fee rate of the config.
+/// The swap has no referrer, all of the protocol fee goes to the pool
+pub fn swap_with_k_token<'info>(
+    ctx: Context<'_, '_, '_, 'info, SwapWithKToken<'info>>,
     amount_in: u64,
     minimum_amount_out: u64,
 ) -> Result<()> {
@@ -111,11 +122,7 @@ pub fn swap_with_k_token(
     let pool_state = &mut ctx.accounts.pool_state.load_mut()?;
     
     // Verify pool is open for swaps
-    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Swap)
-        || block_timestamp < pool_state.open_time
-    {
-        return err!(ErrorCode::NotApproved);
-    }
+    check_swap_status(pool_state, &ctx.accounts.protocol_state, block_timestamp)?;
     
     // Verify fee discount is enabled
     require!(
@@ -217,6 +224,7 @@ pub fn swap_with_k_token(
         ctx.accounts.input_token_program.to_account_info(),
         input_transfer_amount,
         ctx.accounts.input_token_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
     // Transfer K tokens from user to pool's fee vault
@@ -228,6 +236,7 @@ pub fn swap_with_k_token(
         ctx.accounts.k_token_program.to_account_info(),
         k_token_fee_amount,
         ctx.accounts.k_token_mint.decimals,
+        ctx.remaining_accounts,
     )?;
 
     // Transfer output tokens from vault to user
@@ -240,6 +249,27 @@ pub fn swap_with_k_token(
         output_transfer_amount,
         ctx.accounts.output_token_mint.decimals,
         &[&[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]]],
+        ctx.remaining_accounts,
+    )?;
+
+    let swap_prices = SwapPrices::new(
+        pool_state,
+        trade_direction,
+        (token_0_price_x64, token_1_price_x64),
+        &ctx.accounts.input_token_mint.to_account_info(),
+        &ctx.accounts.output_token_mint.to_account_info(),
+        block_timestamp,
+    )?;
+    swap_prices.check_circuit_breaker(
+        pool_state,
+        pool_id,
+        &*ctx.accounts.observation_state.load()?,
+        block_timestamp,
+        trade_direction,
+        (
+            result.new_input_vault_amount,
+            result.new_output_vault_amount,
+        ),
     )?;
 
     // Update pool state with fees
@@ -256,9 +286,11 @@ pub fn swap_with_k_token(
         .checked_add(k_token_fee_amount)
         .ok_or(ErrorCode::MathOverflow)?;
 
-    // Update observation
-    let mut observation_state = ctx.accounts.observation_state.load_mut()?;
-    observation_state.update(block_timestamp, token_0_price_x64, token_1_price_x64);
+    swap_prices.update_observation(
+        pool_state,
+        &mut *ctx.accounts.observation_state.load_mut()?,
+        block_timestamp,
+    )?;
 
     // Emit swap event
     emit!(SwapEvent {
@@ -275,9 +307,18 @@ pub fn swap_with_k_token(
         trade_fee: u64::try_from(result.trade_fee).unwrap(),
         creator_fee: u64::try_from(result.creator_fee).unwrap(),
         creator_fee_on_input: is_creator_fee_on_input,
+        input_ui_amount: to_ui_amount(
+            u64::try_from(result.input_amount).unwrap(),
+            swap_prices.input_ui_multiplier_x32,
+        ),
+        output_ui_amount: to_ui_amount(
+            u64::try_from(result.output_amount).unwrap(),
+            swap_prices.output_ui_multiplier_x32,
+        ),
+        referral_fee: 0,
     });
 
     msg!("K token fee payment: {} tokens", k_token_fee_amount);
 
-    Ok(())
+    SwapReturnData::new(&result, input_transfer_fee, output_transfer_fee, 0).set()
 }
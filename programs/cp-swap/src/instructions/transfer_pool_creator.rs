@@ -0,0 +1,27 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct TransferPoolCreator<'info> {
+    /// The current pool creator
+    #[account(address = pool_state.load()?.pool_creator @ ErrorCode::InvalidOwner)]
+    pub creator: Signer<'info>,
+
+    /// The pool whose creator is transferred
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+pub fn transfer_pool_creator(ctx: Context<TransferPoolCreator>, new_creator: Pubkey) -> Result<()> {
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    pool_state.pending_pool_creator = new_creator;
+
+    emit!(PoolCreatorTransferEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        pool_creator: pool_state.pool_creator,
+        pending_pool_creator: new_creator,
+        accepted: false,
+    });
+    Ok(())
+}
@@ -0,0 +1,94 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    /// The user withdrawing the staked K token
+    pub owner: Signer<'info>,
+
+    /// CHECK: pool vault, lp mint and stake vault authority
+    #[account(
+        seeds = [
+            crate::AUTH_SEED.as_bytes(),
+        ],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+
+    /// The K token mint
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The stake of the user
+    #[account(
+        mut,
+        seeds = [
+            USER_STAKE_SEED.as_bytes(),
+            mint.key().as_ref(),
+            owner.key().as_ref(),
+        ],
+        bump = user_stake.bump,
+    )]
+    pub user_stake: Box<Account<'info, UserStake>>,
+
+    /// The vault holding the staked K tokens
+    #[account(
+        mut,
+        seeds = [
+            STAKE_VAULT_SEED.as_bytes(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The user token account receiving the K token
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub owner_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn unstake<'info>(ctx: Context<'_, '_, '_, 'info, Unstake<'info>>, amount: u64) -> Result<()> {
+    let block_timestamp = Clock::get()?.unix_timestamp as u64;
+    let user_stake = ctx.accounts.user_stake.as_mut();
+    require_gte!(
+        block_timestamp,
+        user_stake.unlock_time,
+        ErrorCode::StakeLocked
+    );
+    require_gt!(amount, 0);
+    user_stake.amount = user_stake
+        .amount
+        .checked_sub(amount)
+        .ok_or(ErrorCode::InvalidInput)?;
+
+    transfer_from_pool_vault_to_user(
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.stake_vault.to_account_info(),
+        ctx.accounts.owner_token_account.to_account_info(),
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        amount,
+        ctx.accounts.mint.decimals,
+        &[&[crate::AUTH_SEED.as_bytes(), &[ctx.bumps.authority]]],
+        ctx.remaining_accounts,
+    )?;
+
+    emit!(StakeEvent {
+        owner: user_stake.owner,
+        mint: user_stake.mint,
+        amount,
+        staked_amount: user_stake.amount,
+        unlock_time: user_stake.unlock_time,
+        change_type: 1,
+    });
+    Ok(())
+}
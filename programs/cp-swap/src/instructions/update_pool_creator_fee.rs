@@ -0,0 +1,53 @@
+use crate::error::ErrorCode;
+use crate::states::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdatePoolCreatorFee<'info> {
+    /// The admin or the pool creator, only the admin can enable the creator fee
+    /// and change the creator fee mode of a pool with `creator_fee_on_locked`
+    #[account(
+        constraint = (authority.key() == pool_state.load()?.pool_creator || authority.key() == protocol_state.admin) @ ErrorCode::InvalidOwner
+    )]
+    pub authority: Signer<'info>,
+
+    /// Protocol state holding the admin
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Account<'info, ProtocolState>,
+
+    /// The pool whose creator fee is updated
+    #[account(mut)]
+    pub pool_state: AccountLoader<'info, PoolState>,
+}
+
+pub fn update_pool_creator_fee(
+    ctx: Context<UpdatePoolCreatorFee>,
+    enable_creator_fee: bool,
+    creator_fee_on: CreatorFeeOn,
+) -> Result<()> {
+    let mut pool_state = ctx.accounts.pool_state.load_mut()?;
+    let (old_enable_creator_fee, old_creator_fee_on) =
+        (pool_state.enable_creator_fee, pool_state.creator_fee_on);
+    if (enable_creator_fee && !old_enable_creator_fee)
+        || (creator_fee_on.to_u8() != old_creator_fee_on && pool_state.creator_fee_on_locked)
+    {
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            ctx.accounts.protocol_state.admin,
+            ErrorCode::InvalidOwner
+        );
+    }
+    pool_state.set_creator_fee(enable_creator_fee, creator_fee_on);
+
+    emit!(CreatorFeeUpdatedEvent {
+        pool_id: ctx.accounts.pool_state.key(),
+        old_enable_creator_fee,
+        new_enable_creator_fee: pool_state.enable_creator_fee,
+        old_creator_fee_on,
+        new_creator_fee_on: pool_state.creator_fee_on,
+    });
+    Ok(())
+}
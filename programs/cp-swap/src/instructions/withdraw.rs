@@ -81,7 +81,7 @@ pub struct Withdraw<'info> {
     )]
     pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// Pool lp token mint
+    /// Pool lp token mint, owned by token program or token program 2022
     #[account(
         mut,
         address = pool_state.load()?.lp_mint @ ErrorCode::IncorrectLpMint)
@@ -94,10 +94,17 @@ pub struct Withdraw<'info> {
         address = spl_memo::id()
     )]
     pub memo_program: UncheckedAccount<'info>,
+
+    /// Protocol state holding the protocol-wide status
+    #[account(
+        seeds = [PROTOCOL_STATE_SEED.as_bytes()],
+        bump = protocol_state.bump,
+    )]
+    pub protocol_state: Box<Account<'info, ProtocolState>>,
 }
 
-pub fn withdraw(
-    ctx: Context<Withdraw>,
+pub fn withdraw<'info>(
+    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
     lp_token_amount: u64,
     minimum_token_0_amount: u64,
     minimum_token_1_amount: u64,
@@ -106,7 +113,12 @@ pub fn withdraw(
     require_gte!(ctx.accounts.owner_lp_token.amount, lp_token_amount);
     let pool_id = ctx.accounts.pool_state.key();
     let pool_state = &mut ctx.accounts.pool_state.load_mut()?;
-    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Withdraw) {
+    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Withdraw)
+        || !ctx
+            .accounts
+            .protocol_state
+            .get_status_by_bit(PoolStatusBitIndex::Withdraw)
+    {
         return err!(ErrorCode::NotApproved);
     }
     let (total_token_0_amount, total_token_1_amount) = pool_state.vault_amount_without_fee(
@@ -178,7 +190,11 @@ pub fn withdraw(
     pool_state.lp_supply = pool_state.lp_supply.checked_sub(lp_token_amount).unwrap();
     token_burn(
         ctx.accounts.owner.to_account_info(),
-        ctx.accounts.token_program.to_account_info(),
+        if ctx.accounts.lp_mint.to_account_info().owner == ctx.accounts.token_program.key {
+            ctx.accounts.token_program.to_account_info()
+        } else {
+            ctx.accounts.token_program_2022.to_account_info()
+        },
         ctx.accounts.lp_mint.to_account_info(),
         ctx.accounts.owner_lp_token.to_account_info(),
         lp_token_amount,
@@ -198,6 +214,7 @@ pub fn withdraw(
         token_0_amount,
         ctx.accounts.vault_0_mint.decimals,
         &[&[crate::AUTH_SEED.as_bytes(), &[pool_state.auth_bump]]],
+        ctx.remaining_accounts,
     )?;
 
     transfer_from_pool_vault_to_user(
@@ -213,8 +230,18 @@ pub fn withdraw(
         token_1_amount,
         ctx.accounts.vault_1_mint.decimals,
         &[&[crate::AUTH_SEED.as_bytes(), &[pool_state.auth_bump]]],
+        ctx.remaining_accounts,
     )?;
     pool_state.recent_epoch = Clock::get()?.epoch;
 
-    Ok(())
+    LpChangeReturnData {
+        lp_token_amount,
+        token_0_amount,
+        token_1_amount,
+        token_0_transfer_fee,
+        token_1_transfer_fee,
+        new_token_0_vault_amount: total_token_0_amount - token_0_amount,
+        new_token_1_vault_amount: total_token_1_amount - token_1_amount,
+    }
+    .set()
 }
@@ -3,10 +3,10 @@ pub mod error;
 pub mod instructions;
 pub mod states;
 pub mod utils;
-use crate::curve::fees::FEE_RATE_DENOMINATOR_VALUE;
 use anchor_lang::prelude::*;
 use instructions::*;
-pub use states::CreatorFeeOn;
+use states::STAKE_TIER_NUM;
+pub use states::{AmmConfigUpdate, CreatorFeeOn, FeeRecipient, LpMintMetadata, PermissionScope};
 
 #[cfg(not(feature = "no-entrypoint"))]
 solana_security_txt::security_txt! {
@@ -24,6 +24,8 @@ declare_id!("DRaycpLY18LhpbydsBWbVJtxpNv9oXPgjRSfpF2bWpYb");
 #[cfg(not(feature = "devnet"))]
 declare_id!("9HXzD3nbdLwdGz3vfrCXw3Dn5JvbqBB8uMGja3aJMuTm");
 
+/// The deploy time admin, only used to initialize the protocol state.
+/// The admin is read from `ProtocolState` afterwards.
 pub mod admin {
     use super::{pubkey, Pubkey};
     #[cfg(feature = "devnet")]
@@ -46,6 +48,37 @@ pub const AUTH_SEED: &str = "vault_and_lp_mint_auth_seed";
 pub mod kedolik_cp_swap {
     use super::*;
 
+    /// Initialize the protocol state holding the admin, must be called by the deploy time admin
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn initialize_protocol_state(ctx: Context<InitializeProtocolState>) -> Result<()> {
+        instructions::initialize_protocol_state(ctx)
+    }
+
+    /// Propose a new admin, it takes effect once the new admin accepts
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `new_admin` - The proposed admin, default pubkey cancels the proposal
+    ///
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::transfer_admin(ctx, new_admin)
+    }
+
+    /// Accept the admin transfer, must be called by the pending admin
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(ctx)
+    }
+
     // The configuration of AMM protocol, include trade fee and protocol fee
     /// # Arguments
     ///
@@ -66,11 +99,6 @@ pub mod kedolik_cp_swap {
         fee_token_mint: Pubkey,
         fee_token_discount_rate: u64,
     ) -> Result<()> {
-        assert!(trade_fee_rate + creator_fee_rate < FEE_RATE_DENOMINATOR_VALUE);
-        assert!(protocol_fee_rate <= FEE_RATE_DENOMINATOR_VALUE);
-        assert!(fund_fee_rate <= FEE_RATE_DENOMINATOR_VALUE);
-        assert!(fund_fee_rate + protocol_fee_rate <= FEE_RATE_DENOMINATOR_VALUE);
-        assert!(fee_token_discount_rate <= 10000); // Max 100% discount
         instructions::create_amm_config(
             ctx,
             index,
@@ -84,21 +112,65 @@ pub mod kedolik_cp_swap {
         )
     }
 
-    /// Updates the owner of the amm config
-    /// Must be called by the current owner or admin
+    /// Updates the amm config
+    /// Must be called by the admin
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `params`- The fields to be updated, `None` keeps the current value,
+    ///   fee and owner changes must be queued when the timelock is enabled
+    ///
+    pub fn update_amm_config(ctx: Context<UpdateAmmConfig>, params: AmmConfigUpdate) -> Result<()> {
+        instructions::update_amm_config(ctx, params)
+    }
+
+    /// Update the timelock delay of config updates, the delay can only be increased
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `timelock_delay` - Seconds a queued config update must wait before execution
+    ///
+    pub fn update_timelock_delay(
+        ctx: Context<UpdateTimelockDelay>,
+        timelock_delay: u64,
+    ) -> Result<()> {
+        instructions::update_timelock_delay(ctx, timelock_delay)
+    }
+
+    /// Queue a config update, it can be executed after the timelock delay
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `params`- The fields to be updated, `None` keeps the current value
+    ///
+    pub fn queue_amm_config_update(
+        ctx: Context<QueueAmmConfigUpdate>,
+        params: AmmConfigUpdate,
+    ) -> Result<()> {
+        instructions::queue_amm_config_update(ctx, params)
+    }
+
+    /// Execute the queued config update once the timelock delay has passed
     ///
     /// # Arguments
     ///
     /// * `ctx`- The context of accounts
-    /// * `trade_fee_rate`- The new trade fee rate of amm config, be set when `param` is 0
-    /// * `protocol_fee_rate`- The new protocol fee rate of amm config, be set when `param` is 1
-    /// * `fund_fee_rate`- The new fund fee rate of amm config, be set when `param` is 2
-    /// * `new_owner`- The config's new owner, be set when `param` is 3
-    /// * `new_fund_owner`- The config's new fund owner, be set when `param` is 4
-    /// * `param`- The value can be 0 | 1 | 2 | 3 | 4, otherwise will report a error
     ///
-    pub fn update_amm_config(ctx: Context<UpdateAmmConfig>, param: u8, value: u64) -> Result<()> {
-        instructions::update_amm_config(ctx, param, value)
+    pub fn execute_amm_config_update(ctx: Context<ExecuteAmmConfigUpdate>) -> Result<()> {
+        instructions::execute_amm_config_update(ctx)
+    }
+
+    /// Cancel the queued config update
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn cancel_amm_config_update(ctx: Context<CancelAmmConfigUpdate>) -> Result<()> {
+        instructions::cancel_amm_config_update(ctx)
     }
 
     /// Update pool status for given value
@@ -112,6 +184,87 @@ pub mod kedolik_cp_swap {
         instructions::update_pool_status(ctx, status)
     }
 
+    /// Update the circuit breaker of a pool, guarding swaps against the observation twap
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `max_deviation_bps` - The max deviation of the post swap price from the twap in basis points, 0 disables the circuit breaker
+    /// * `window` - The seconds of observations the post swap price is compared against, at most `MAX_CIRCUIT_BREAKER_WINDOW`
+    /// * `pause` - Pause swaps of the pool after a swap beyond the deviation instead of rejecting it
+    ///
+    pub fn set_circuit_breaker(
+        ctx: Context<SetCircuitBreaker>,
+        max_deviation_bps: u16,
+        window: u64,
+        pause: bool,
+    ) -> Result<()> {
+        instructions::set_circuit_breaker(ctx, max_deviation_bps, window, pause)
+    }
+
+    /// Resume swaps of a pool paused by its circuit breaker
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
+        instructions::reset_circuit_breaker(ctx)
+    }
+
+    /// Set the guardian, who can only disable pools
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `guardian` - The new guardian, default pubkey removes the guardian
+    ///
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        instructions::set_guardian(ctx, guardian)
+    }
+
+    /// Disable the status bits of the pools under the config, pools are passed as remaining accounts
+    /// Must be called by the guardian or admin
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `status` - The bits to disable, bit0: deposit, bit1: withdraw, bit2: swap
+    ///
+    pub fn pause_pools<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PausePools<'info>>,
+        status: u8,
+    ) -> Result<()> {
+        instructions::pause_pools(ctx, status)
+    }
+
+    /// Update the protocol-wide status, checked by every pool alongside its own status
+    /// The guardian can only disable, the admin can also re-enable
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `status` - The value of status, bit0: deposit, bit1: withdraw, bit2: swap
+    ///
+    pub fn update_protocol_status(ctx: Context<UpdateProtocolStatus>, status: u8) -> Result<()> {
+        instructions::update_protocol_status(ctx, status)
+    }
+
+    /// Update whether the pool observation records prices in ui amount space
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `ui_amount_pricing` - Price in ui amount space for interest bearing mints,
+    ///   switching it resets the observations of the pool
+    ///
+    pub fn update_pool_ui_amount_pricing(
+        ctx: Context<UpdatePoolUiAmountPricing>,
+        ui_amount_pricing: bool,
+    ) -> Result<()> {
+        instructions::update_pool_ui_amount_pricing(ctx, ui_amount_pricing)
+    }
+
     /// Collect the protocol fee accrued to the pool
     ///
     /// # Arguments
@@ -120,14 +273,45 @@ pub mod kedolik_cp_swap {
     /// * `amount_0_requested` - The maximum amount of token_0 to send, can be 0 to collect fees in only token_1
     /// * `amount_1_requested` - The maximum amount of token_1 to send, can be 0 to collect fees in only token_0
     ///
-    pub fn collect_protocol_fee(
-        ctx: Context<CollectProtocolFee>,
+    pub fn collect_protocol_fee<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectProtocolFee<'info>>,
         amount_0_requested: u64,
         amount_1_requested: u64,
     ) -> Result<()> {
         instructions::collect_protocol_fee(ctx, amount_0_requested, amount_1_requested)
     }
 
+    /// Set the pool protocol fees are swapped through to buy back the K token
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `buyback_destination` - The token account receiving the K token, default pubkey burns it
+    ///
+    pub fn set_buyback_config(
+        ctx: Context<SetBuybackConfig>,
+        buyback_destination: Pubkey,
+    ) -> Result<()> {
+        instructions::set_buyback_config(ctx, buyback_destination)
+    }
+
+    /// Swap the protocol fees accrued to the pool for the K token through the buyback pool,
+    /// then burn it or send it to the buyback destination
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `amount_requested` - The maximum amount of protocol fees to spend
+    /// * `minimum_amount_out` - The minimum amount of K token to buy back
+    ///
+    pub fn buyback_protocol_fee<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuybackProtocolFee<'info>>,
+        amount_requested: u64,
+        minimum_amount_out: u64,
+    ) -> Result<()> {
+        instructions::buyback_protocol_fee(ctx, amount_requested, minimum_amount_out)
+    }
+
     /// Collect the fund fee accrued to the pool
     ///
     /// # Arguments
@@ -136,32 +320,357 @@ pub mod kedolik_cp_swap {
     /// * `amount_0_requested` - The maximum amount of token_0 to send, can be 0 to collect fees in only token_1
     /// * `amount_1_requested` - The maximum amount of token_1 to send, can be 0 to collect fees in only token_0
     ///
-    pub fn collect_fund_fee(
-        ctx: Context<CollectFundFee>,
+    pub fn collect_fund_fee<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectFundFee<'info>>,
         amount_0_requested: u64,
         amount_1_requested: u64,
     ) -> Result<()> {
         instructions::collect_fund_fee(ctx, amount_0_requested, amount_1_requested)
     }
 
+    /// Update the K token staking tiers of the config.
+    /// Fails with `TimelockRequired` when the timelock is enabled, the tiers are then queued with `queue_amm_config_update`
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `stake_tier_thresholds` - The staked amounts to reach the tiers, ascending, 0 disables the tier and the tiers after it
+    /// * `stake_tier_discount_rates` - The trade fee discount of the tiers, denominated in basis points, non-decreasing
+    ///
+    pub fn update_stake_tiers(
+        ctx: Context<UpdateStakeTiers>,
+        stake_tier_thresholds: [u64; STAKE_TIER_NUM],
+        stake_tier_discount_rates: [u64; STAKE_TIER_NUM],
+    ) -> Result<()> {
+        instructions::update_stake_tiers(ctx, stake_tier_thresholds, stake_tier_discount_rates)
+    }
+
+    /// Set the weighted recipients of the protocol or fund fees of the pools under a config,
+    /// signed by the protocol_owner or fund_owner of the config
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    /// * `fee_kind` - 0: protocol fees, 1: fund fees
+    /// * `recipients` - The recipients and their shares in basis points, the shares must sum to 10000
+    ///
+    pub fn set_fee_distribution(
+        ctx: Context<SetFeeDistribution>,
+        fee_kind: u8,
+        recipients: Vec<FeeRecipient>,
+    ) -> Result<()> {
+        instructions::set_fee_distribution(ctx, fee_kind, recipients)
+    }
+
+    /// Collect all the protocol or fund fees of a pool and split them between the recipients
+    /// of the fee distribution, can be called by anyone
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    ///
+    pub fn distribute_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DistributeFees<'info>>,
+    ) -> Result<()> {
+        instructions::distribute_fees(ctx)
+    }
+
+    /// Sweep the protocol and fund fees of many pools under a config into the owners' token accounts,
+    /// can be called by anyone. Stops early when the compute units run low.
+    /// Pools with transfer hook mints and the fees of a kind with a fee distribution are skipped
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context of accounts
+    ///
+    pub fn collect_fees_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CollectFeesBatch<'info>>,
+    ) -> Result<()> {
+        instructions::collect_fees_batch(ctx)
+    }
+
     /// Collect the creator fee
     ///
     /// # Arguments
     ///
     /// * `ctx` - The context of accounts
     ///
-    pub fn collect_creator_fee(ctx: Context<CollectCreatorFee>) -> Result<()> {
+    pub fn collect_creator_fee<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectCreatorFee<'info>>,
+    ) -> Result<()> {
         instructions::collect_creator_fee(ctx)
     }
 
+    /// Propose a new pool creator, it takes effect once the new creator accepts
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `new_creator` - The proposed pool creator, default pubkey cancels the proposal
+    ///
+    pub fn transfer_pool_creator(
+        ctx: Context<TransferPoolCreator>,
+        new_creator: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_pool_creator(ctx, new_creator)
+    }
+
+    /// Accept the pool creator transfer, must be called by the pending pool creator
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn accept_pool_creator(ctx: Context<AcceptPoolCreator>) -> Result<()> {
+        instructions::accept_pool_creator(ctx)
+    }
+
+    /// Set the address receiving the creator fees of the pool
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `recipient` - The creator fee recipient, default pubkey means the pool creator
+    ///
+    pub fn set_creator_fee_recipient(
+        ctx: Context<SetCreatorFeeRecipient>,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::set_creator_fee_recipient(ctx, recipient)
+    }
+
+    /// Turn the creator fee on/off and change the creator fee mode of a pool,
+    /// accrued creator fees stay collectable. Only the admin can enable the creator fee, and change
+    /// the mode of pools created with a permission restricted to `CreatorFeeOn::BothToken`
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `enable_creator_fee` - whether the pool charges the creator fee
+    /// * `creator_fee_on` - creator fee model, 0：both token0 and token1 (depends on the input), 1: only token0, 2: only token1
+    ///
+    pub fn update_pool_creator_fee(
+        ctx: Context<UpdatePoolCreatorFee>,
+        enable_creator_fee: bool,
+        creator_fee_on: CreatorFeeOn,
+    ) -> Result<()> {
+        instructions::update_pool_creator_fee(ctx, enable_creator_fee, creator_fee_on)
+    }
+
+    /// Stake K tokens to reach the staking tiers which discount the trade fee
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `amount` - The amount of K token to stake, locked for `STAKE_LOCK_DURATION`
+    ///
+    pub fn stake<'info>(ctx: Context<'_, '_, '_, 'info, Stake<'info>>, amount: u64) -> Result<()> {
+        instructions::stake(ctx, amount)
+    }
+
+    /// Withdraw staked K tokens once unlocked
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `amount` - The amount of K token to withdraw
+    ///
+    pub fn unstake<'info>(
+        ctx: Context<'_, '_, '_, 'info, Unstake<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::unstake(ctx, amount)
+    }
+
+    /// Place a limit order escrowing the input token, filled against the pool by a keeper
+    /// once the swap of the whole order gets the target price
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `nonce` - The owner chosen nonce of the order
+    /// * `amount_in` - The amount of input token to escrow
+    /// * `target_price_x32` - The price of the input token denominated in the output token the fill has to get, Q32.32
+    /// * `minimum_amount_out` - Minimum amount of output token to receive on fill, prevents excessive slippage
+    /// * `keeper_bounty` - The lamports paid to the keeper filling the order
+    ///
+    pub fn place_limit_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceLimitOrder<'info>>,
+        nonce: u64,
+        amount_in: u64,
+        target_price_x32: u128,
+        minimum_amount_out: u64,
+        keeper_bounty: u64,
+    ) -> Result<()> {
+        instructions::place_limit_order(
+            ctx,
+            nonce,
+            amount_in,
+            target_price_x32,
+            minimum_amount_out,
+            keeper_bounty,
+        )
+    }
+
+    /// Cancel a limit order, returning the escrowed tokens and the keeper bounty to the owner
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn cancel_limit_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, CancelLimitOrder<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_limit_order(ctx)
+    }
+
+    /// Fill a limit order against the pool, permissionless, the keeper receives the order bounty
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn fill_limit_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillLimitOrder<'info>>,
+    ) -> Result<()> {
+        instructions::fill_limit_order(ctx)
+    }
+
+    /// Place a long term order selling the input token at a constant rate over the duration,
+    /// executed against the pool in increments by a permissionless crank.
+    /// Swaps of other users do not execute the order, it is only sold by `execute_long_term_order`
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `nonce` - The owner chosen nonce of the order
+    /// * `amount_in` - The amount of input token to escrow and sell
+    /// * `duration` - The seconds over which the amount is sold
+    /// * `max_amount_per_execution` - The max amount of input token sold by one execution
+    /// * `max_twap_deviation_bps` - The max deviation of the execution price below the twap of the input token, in basis points, has to exceed the trade fee rate
+    ///
+    pub fn place_long_term_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceLongTermOrder<'info>>,
+        nonce: u64,
+        amount_in: u64,
+        duration: u64,
+        max_amount_per_execution: u64,
+        max_twap_deviation_bps: u64,
+    ) -> Result<()> {
+        instructions::place_long_term_order(
+            ctx,
+            nonce,
+            amount_in,
+            duration,
+            max_amount_per_execution,
+            max_twap_deviation_bps,
+        )
+    }
+
+    /// Sell the amount of a long term order due since its latest execution, at most the max
+    /// amount per execution and within the max twap deviation of the order, permissionless
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn execute_long_term_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteLongTermOrder<'info>>,
+    ) -> Result<()> {
+        instructions::execute_long_term_order(ctx)
+    }
+
+    /// Close a long term order, returning the unsold and the bought tokens to the owner
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn close_long_term_order<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseLongTermOrder<'info>>,
+    ) -> Result<()> {
+        instructions::close_long_term_order(ctx)
+    }
+
+    /// Open a DCA vault selling a fixed amount of the input token through the pool every period
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `nonce` - The owner chosen nonce of the DCA vault
+    /// * `amount_in` - The amount of input token to deposit
+    /// * `amount_per_period` - The amount of input token sold every period
+    /// * `period` - The seconds between executions
+    /// * `max_twap_deviation_bps` - The max deviation of the execution price below the twap of the input token, in basis points, has to exceed the trade fee rate
+    ///
+    pub fn open_dca_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, OpenDcaVault<'info>>,
+        nonce: u64,
+        amount_in: u64,
+        amount_per_period: u64,
+        period: u64,
+        max_twap_deviation_bps: u64,
+    ) -> Result<()> {
+        instructions::open_dca_vault(
+            ctx,
+            nonce,
+            amount_in,
+            amount_per_period,
+            period,
+            max_twap_deviation_bps,
+        )
+    }
+
+    /// Sell the input token of the current period of a DCA vault, permissionless
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn execute_dca_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteDcaVault<'info>>,
+    ) -> Result<()> {
+        instructions::execute_dca_vault(ctx)
+    }
+
+    /// Close a DCA vault, returning the unsold and the bought tokens to the owner
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn close_dca_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseDcaVault<'info>>,
+    ) -> Result<()> {
+        instructions::close_dca_vault(ctx)
+    }
+
     /// Create a permission account
     ///
     /// # Arguments
     ///
     /// * `ctx`- The context of accounts
+    /// * `scope` - The allowed configs, expiry, max pool count and privileges of the permission
     ///
-    pub fn create_permission_pda(ctx: Context<CreatePermissionPda>) -> Result<()> {
-        instructions::create_permission_pda(ctx)
+    pub fn create_permission_pda(
+        ctx: Context<CreatePermissionPda>,
+        scope: PermissionScope,
+    ) -> Result<()> {
+        instructions::create_permission_pda(ctx, scope)
+    }
+
+    /// Update the scope of a permission account
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `scope` - The allowed configs, expiry, max pool count and privileges of the permission
+    ///
+    pub fn update_permission_pda(
+        ctx: Context<UpdatePermissionPda>,
+        scope: PermissionScope,
+    ) -> Result<()> {
+        instructions::update_permission_pda(ctx, scope)
     }
 
     /// Close a permission account
@@ -174,6 +683,46 @@ pub mod kedolik_cp_swap {
         instructions::close_permission_pda(ctx)
     }
 
+    /// Approve a transfer hook program, mints using it can be added to pools
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn create_transfer_hook_approval(ctx: Context<CreateTransferHookApproval>) -> Result<()> {
+        instructions::create_transfer_hook_approval(ctx)
+    }
+
+    /// Revoke the approval of a transfer hook program, transfers of mints using it fail
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn close_transfer_hook_approval(ctx: Context<CloseTransferHookApproval>) -> Result<()> {
+        instructions::close_transfer_hook_approval(ctx)
+    }
+
+    /// Add a token-2022 mint to the allowlist, it can be used in pools regardless of its extensions
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn add_allowed_mint(ctx: Context<AddAllowedMint>) -> Result<()> {
+        instructions::add_allowed_mint(ctx)
+    }
+
+    /// Remove a token-2022 mint from the allowlist
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    ///
+    pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>) -> Result<()> {
+        instructions::remove_allowed_mint(ctx)
+    }
+
     /// Creates a pool for the given token pair and the initial price
     ///
     /// # Arguments
@@ -182,14 +731,22 @@ pub mod kedolik_cp_swap {
     /// * `init_amount_0` - the initial amount_0 to deposit
     /// * `init_amount_1` - the initial amount_1 to deposit
     /// * `open_time` - the timestamp allowed for swap
+    /// * `lp_mint_metadata` - if provided, the lp mint is created under token program 2022 with token metadata
     ///
-    pub fn initialize(
-        ctx: Context<Initialize>,
+    pub fn initialize<'info>(
+        ctx: Context<'_, '_, '_, 'info, Initialize<'info>>,
         init_amount_0: u64,
         init_amount_1: u64,
         open_time: u64,
+        lp_mint_metadata: Option<LpMintMetadata>,
     ) -> Result<()> {
-        instructions::initialize(ctx, init_amount_0, init_amount_1, open_time)
+        instructions::initialize(
+            ctx,
+            init_amount_0,
+            init_amount_1,
+            open_time,
+            lp_mint_metadata,
+        )
     }
 
     /// Create a pool with permission
@@ -201,13 +758,17 @@ pub mod kedolik_cp_swap {
     /// * `init_amount_1` - the initial amount_1 to deposit
     /// * `open_time` - the timestamp allowed for swap
     /// * `creator_fee_on` - creator fee model, 0：both token0 and token1 (depends on the input), 1: only token0, 2: only token1
+    /// * `enable_creator_fee` - whether the pool charges the creator fee
+    /// * `lp_mint_metadata` - if provided, the lp mint is created under token program 2022 with token metadata
     ///
-    pub fn initialize_with_permission(
-        ctx: Context<InitializeWithPermission>,
+    pub fn initialize_with_permission<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitializeWithPermission<'info>>,
         init_amount_0: u64,
         init_amount_1: u64,
         open_time: u64,
         creator_fee_on: CreatorFeeOn,
+        enable_creator_fee: bool,
+        lp_mint_metadata: Option<LpMintMetadata>,
     ) -> Result<()> {
         instructions::initialize_with_permission(
             ctx,
@@ -215,6 +776,8 @@ pub mod kedolik_cp_swap {
             init_amount_1,
             open_time,
             creator_fee_on,
+            enable_creator_fee,
+            lp_mint_metadata,
         )
     }
 
@@ -227,8 +790,8 @@ pub mod kedolik_cp_swap {
     /// * `maximum_token_0_amount` -  Maximum token 0 amount to deposit, prevents excessive slippage
     /// * `maximum_token_1_amount` - Maximum token 1 amount to deposit, prevents excessive slippage
     ///
-    pub fn deposit(
-        ctx: Context<Deposit>,
+    pub fn deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
         lp_token_amount: u64,
         maximum_token_0_amount: u64,
         maximum_token_1_amount: u64,
@@ -250,8 +813,8 @@ pub mod kedolik_cp_swap {
     /// * `minimum_token_0_amount` -  Minimum amount of token 0 to receive, prevents excessive slippage
     /// * `minimum_token_1_amount` -  Minimum amount of token 1 to receive, prevents excessive slippage
     ///
-    pub fn withdraw(
-        ctx: Context<Withdraw>,
+    pub fn withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
         lp_token_amount: u64,
         minimum_token_0_amount: u64,
         minimum_token_1_amount: u64,
@@ -271,13 +834,23 @@ pub mod kedolik_cp_swap {
     /// * `ctx`- The context of accounts
     /// * `amount_in` -  input amount to transfer, output to DESTINATION is based on the exchange rate
     /// * `minimum_amount_out` -  Minimum amount of output token, prevents excessive slippage
+    /// * `deadline` - The timestamp after which the swap is rejected, 0 means no deadline
+    /// * `price_limit_x32` - The minimum price of the input token denominated in the output token after the swap, Q32.32, 0 means no limit
     ///
-    pub fn swap_base_input(
-        ctx: Context<Swap>,
+    pub fn swap_base_input<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
         amount_in: u64,
         minimum_amount_out: u64,
+        deadline: u64,
+        price_limit_x32: u128,
     ) -> Result<()> {
-        instructions::swap_base_input(ctx, amount_in, minimum_amount_out)
+        instructions::swap_base_input(
+            ctx,
+            amount_in,
+            minimum_amount_out,
+            deadline,
+            price_limit_x32,
+        )
     }
 
     /// Swap the tokens in the pool base output amount
@@ -287,20 +860,92 @@ pub mod kedolik_cp_swap {
     /// * `ctx`- The context of accounts
     /// * `max_amount_in` -  input amount prevents excessive slippage
     /// * `amount_out` -  amount of output token
+    /// * `deadline` - The timestamp after which the swap is rejected, 0 means no deadline
+    /// * `price_limit_x32` - The minimum price of the input token denominated in the output token after the swap, Q32.32, 0 means no limit
+    ///
+    pub fn swap_base_output<'info>(
+        ctx: Context<'_, '_, '_, 'info, Swap<'info>>,
+        max_amount_in: u64,
+        amount_out: u64,
+        deadline: u64,
+        price_limit_x32: u128,
+    ) -> Result<()> {
+        instructions::swap_base_output(ctx, max_amount_in, amount_out, deadline, price_limit_x32)
+    }
+
+    /// Quote a swap base input amount, the result is set as return data and no tokens are moved.
+    /// Fails like `swap_base_input` when the pool or the protocol cannot swap, the price limit is
+    /// exceeded or the circuit breaker rejects the swap, and with the same user stake and referrer
+    /// the return data matches the swap
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `amount_in` -  input amount to transfer, including the transfer fee
+    /// * `price_limit_x32` - The minimum price of the input token denominated in the output token after the swap, Q32.32, 0 means no limit
+    ///
+    pub fn quote_swap_base_input(
+        ctx: Context<QuoteSwap>,
+        amount_in: u64,
+        price_limit_x32: u128,
+    ) -> Result<()> {
+        instructions::quote_swap_base_input(ctx, amount_in, price_limit_x32)
+    }
+
+    /// Quote a swap base output amount, the result is set as return data and no tokens are moved.
+    /// Fails like `swap_base_output` when the pool or the protocol cannot swap, the price limit is
+    /// exceeded or the circuit breaker rejects the swap, and with the same user stake and referrer
+    /// the return data matches the swap
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `amount_out` -  amount of output token to receive, excluding the transfer fee
+    /// * `price_limit_x32` - The minimum price of the input token denominated in the output token after the swap, Q32.32, 0 means no limit
+    ///
+    pub fn quote_swap_base_output(
+        ctx: Context<QuoteSwap>,
+        amount_out: u64,
+        price_limit_x32: u128,
+    ) -> Result<()> {
+        instructions::quote_swap_base_output(ctx, amount_out, price_limit_x32)
+    }
+
+    /// Quote a deposit, the result is set as return data and no tokens are moved.
+    /// Fails like `deposit` when the pool or the protocol cannot deposit
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `lp_token_amount` - Increased number of LPs
+    ///
+    pub fn quote_deposit(ctx: Context<QuoteLpChange>, lp_token_amount: u64) -> Result<()> {
+        instructions::quote_deposit(ctx, lp_token_amount)
+    }
+
+    /// Quote a withdraw, the result is set as return data and no tokens are moved.
+    /// Fails like `withdraw` when the pool or the protocol cannot withdraw
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx`- The context of accounts
+    /// * `lp_token_amount` - Amount of pool tokens to burn
     ///
-    pub fn swap_base_output(ctx: Context<Swap>, max_amount_in: u64, amount_out: u64) -> Result<()> {
-        instructions::swap_base_output(ctx, max_amount_in, amount_out)
+    pub fn quote_withdraw(ctx: Context<QuoteLpChange>, lp_token_amount: u64) -> Result<()> {
+        instructions::quote_withdraw(ctx, lp_token_amount)
     }
 
     /// Swap tokens with K token fee payment for 20% discount
+    /// The staking tier discount does not stack with the K token payment and is not applied,
+    /// and the swap pays no referral fee
     /// 
     /// # Arguments
     /// 
     /// * `amount_in` - Amount of input token to swap
     /// * `minimum_amount_out` - Minimum amount of output token to receive (slippage protection)
     /// 
-    pub fn swap_with_k_token(
-        ctx: Context<SwapWithKToken>,
+    pub fn swap_with_k_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, SwapWithKToken<'info>>,
         amount_in: u64,
         minimum_amount_out: u64,
     ) -> Result<()> {
@@ -1,6 +1,22 @@
+use crate::curve::fees::FEE_RATE_DENOMINATOR_VALUE;
+use crate::error::ErrorCode;
 use anchor_lang::prelude::*;
+use std::ops::BitAnd;
 
 pub const AMM_CONFIG_SEED: &str = "amm_config";
+/// Denominator of `fee_token_discount_rate`
+pub const FEE_TOKEN_DISCOUNT_RATE_DENOMINATOR: u64 = 10000;
+/// All bits of `MintPolicyBitIndex`
+pub const MINT_POLICY_MASK: u64 = 0b111;
+/// Number of K token staking tiers, e.g. bronze, silver and gold
+pub const STAKE_TIER_NUM: usize = 3;
+
+/// Risky mint properties that can be rejected by the config
+pub enum MintPolicyBitIndex {
+    FreezeAuthority,
+    PermanentDelegate,
+    MintAuthority,
+}
 
 /// Holds the current owner of the factory
 #[account]
@@ -31,10 +47,334 @@ pub struct AmmConfig {
     /// The discount rate when paying with fee token, denominated in basis points (10000 = 100%)
     /// Example: 2000 = 20% discount
     pub fee_token_discount_rate: u64,
+    /// Bitwise representation of the mints rejected when creating a pool
+    /// bit0, 1: reject mints with a freeze authority(value is 1), 0: allow
+    /// bit1, 1: reject mints with a permanent delegate(value is 2), 0: allow
+    /// bit2, 1: reject mints with a mint authority(value is 4), 0: allow
+    pub mint_policy: u64,
+    /// The K token staked amounts to reach the staking tiers, ascending, 0 disables the tier and the tiers after it
+    pub stake_tier_thresholds: [u64; STAKE_TIER_NUM],
+    /// The trade fee discount of the staking tiers, denominated in basis points (10000 = 100%), non-decreasing
+    pub stake_tier_discount_rates: [u64; STAKE_TIER_NUM],
+    /// The share of the protocol fee paid to the swap referrer, denominated in hundredths of a bip (10^-6)
+    pub referral_fee_rate: u64,
     /// padding
-    pub padding: [u64; 10],
+    pub padding: [u64; 2],
 }
 
 impl AmmConfig {
-    pub const LEN: usize = 8 + 1 + 1 + 2 + 4 * 8 + 32 * 2 + 8 + 32 + 8 + 8 * 10;
+    pub const LEN: usize =
+        8 + 1 + 1 + 2 + 4 * 8 + 32 * 2 + 8 + 32 + 8 + 8 + 8 * STAKE_TIER_NUM * 2 + 8 + 8 * 2;
+
+    /// Get mint policy by bit, if the mint property is allowed, return true
+    pub fn get_mint_policy_by_bit(&self, bit: MintPolicyBitIndex) -> bool {
+        let policy = 1u64 << (bit as u8);
+        self.mint_policy.bitand(policy) == 0
+    }
+
+    /// Check the fee rates and policy of the config are consistent
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.trade_fee_rate
+                .checked_add(self.creator_fee_rate)
+                .ok_or(ErrorCode::MathOverflow)?
+                < FEE_RATE_DENOMINATOR_VALUE,
+            ErrorCode::InvalidFeeRate
+        );
+        require!(
+            self.protocol_fee_rate
+                .checked_add(self.fund_fee_rate)
+                .ok_or(ErrorCode::MathOverflow)?
+                <= FEE_RATE_DENOMINATOR_VALUE,
+            ErrorCode::InvalidFeeRate
+        );
+        require!(
+            self.fee_token_discount_rate <= FEE_TOKEN_DISCOUNT_RATE_DENOMINATOR,
+            ErrorCode::InvalidFeeRate
+        );
+        require!(
+            self.referral_fee_rate <= FEE_RATE_DENOMINATOR_VALUE,
+            ErrorCode::InvalidFeeRate
+        );
+        require!(
+            self.mint_policy & !MINT_POLICY_MASK == 0,
+            ErrorCode::InvalidInput
+        );
+        for i in 0..STAKE_TIER_NUM {
+            require!(
+                self.stake_tier_discount_rates[i] <= FEE_TOKEN_DISCOUNT_RATE_DENOMINATOR,
+                ErrorCode::InvalidFeeRate
+            );
+            if i > 0 && self.stake_tier_thresholds[i] != 0 {
+                // a disabled tier disables the tiers after it
+                require!(
+                    self.stake_tier_thresholds[i - 1] != 0
+                        && self.stake_tier_thresholds[i] > self.stake_tier_thresholds[i - 1],
+                    ErrorCode::InvalidInput
+                );
+                require!(
+                    self.stake_tier_discount_rates[i] >= self.stake_tier_discount_rates[i - 1],
+                    ErrorCode::InvalidFeeRate
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The trade fee discount of the highest staking tier reached by the staked amount
+    pub fn get_stake_discount_rate(&self, staked_amount: u64) -> u64 {
+        let mut discount_rate = 0;
+        for i in 0..STAKE_TIER_NUM {
+            if self.stake_tier_thresholds[i] != 0 && staked_amount >= self.stake_tier_thresholds[i]
+            {
+                discount_rate = self.stake_tier_discount_rates[i];
+            }
+        }
+        discount_rate
+    }
+
+    /// The trade fee rate after the staking tier discount of the staked amount
+    pub fn get_staker_trade_fee_rate(&self, staked_amount: u64) -> u64 {
+        let discount_rate = self.get_stake_discount_rate(staked_amount);
+        (u128::from(self.trade_fee_rate)
+            * u128::from(FEE_TOKEN_DISCOUNT_RATE_DENOMINATOR - discount_rate)
+            / u128::from(FEE_TOKEN_DISCOUNT_RATE_DENOMINATOR)) as u64
+    }
+
+    /// Apply the provided fields of `params` and validate the result
+    pub fn update(&mut self, params: &AmmConfigUpdate) -> Result<()> {
+        if let Some(trade_fee_rate) = params.trade_fee_rate {
+            self.trade_fee_rate = trade_fee_rate;
+        }
+        if let Some(protocol_fee_rate) = params.protocol_fee_rate {
+            self.protocol_fee_rate = protocol_fee_rate;
+        }
+        if let Some(fund_fee_rate) = params.fund_fee_rate {
+            self.fund_fee_rate = fund_fee_rate;
+        }
+        if let Some(creator_fee_rate) = params.creator_fee_rate {
+            self.creator_fee_rate = creator_fee_rate;
+        }
+        if let Some(create_pool_fee) = params.create_pool_fee {
+            self.create_pool_fee = create_pool_fee;
+        }
+        if let Some(disable_create_pool) = params.disable_create_pool {
+            self.disable_create_pool = disable_create_pool;
+        }
+        if let Some(protocol_owner) = params.protocol_owner {
+            require_keys_neq!(protocol_owner, Pubkey::default(), ErrorCode::InvalidInput);
+            self.protocol_owner = protocol_owner;
+        }
+        if let Some(fund_owner) = params.fund_owner {
+            require_keys_neq!(fund_owner, Pubkey::default(), ErrorCode::InvalidInput);
+            self.fund_owner = fund_owner;
+        }
+        if let Some(fee_token_mint) = params.fee_token_mint {
+            self.fee_token_mint = fee_token_mint;
+        }
+        if let Some(fee_token_discount_rate) = params.fee_token_discount_rate {
+            self.fee_token_discount_rate = fee_token_discount_rate;
+        }
+        if let Some(mint_policy) = params.mint_policy {
+            self.mint_policy = mint_policy;
+        }
+        if let Some(referral_fee_rate) = params.referral_fee_rate {
+            self.referral_fee_rate = referral_fee_rate;
+        }
+        if let Some(stake_tier_thresholds) = params.stake_tier_thresholds {
+            self.stake_tier_thresholds = stake_tier_thresholds;
+        }
+        if let Some(stake_tier_discount_rates) = params.stake_tier_discount_rates {
+            self.stake_tier_discount_rates = stake_tier_discount_rates;
+        }
+        self.validate()
+    }
+
+    pub fn snapshot(&self) -> AmmConfigSnapshot {
+        AmmConfigSnapshot {
+            trade_fee_rate: self.trade_fee_rate,
+            protocol_fee_rate: self.protocol_fee_rate,
+            fund_fee_rate: self.fund_fee_rate,
+            creator_fee_rate: self.creator_fee_rate,
+            create_pool_fee: self.create_pool_fee,
+            disable_create_pool: self.disable_create_pool,
+            protocol_owner: self.protocol_owner,
+            fund_owner: self.fund_owner,
+            fee_token_mint: self.fee_token_mint,
+            fee_token_discount_rate: self.fee_token_discount_rate,
+            mint_policy: self.mint_policy,
+            referral_fee_rate: self.referral_fee_rate,
+            stake_tier_thresholds: self.stake_tier_thresholds,
+            stake_tier_discount_rates: self.stake_tier_discount_rates,
+        }
+    }
+}
+
+/// Fields of the amm config to be updated, `None` keeps the current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AmmConfigUpdate {
+    pub trade_fee_rate: Option<u64>,
+    pub protocol_fee_rate: Option<u64>,
+    pub fund_fee_rate: Option<u64>,
+    pub creator_fee_rate: Option<u64>,
+    pub create_pool_fee: Option<u64>,
+    pub disable_create_pool: Option<bool>,
+    pub protocol_owner: Option<Pubkey>,
+    pub fund_owner: Option<Pubkey>,
+    pub fee_token_mint: Option<Pubkey>,
+    pub fee_token_discount_rate: Option<u64>,
+    pub mint_policy: Option<u64>,
+    pub referral_fee_rate: Option<u64>,
+    pub stake_tier_thresholds: Option<[u64; STAKE_TIER_NUM]>,
+    pub stake_tier_discount_rates: Option<[u64; STAKE_TIER_NUM]>,
+}
+
+impl AmmConfigUpdate {
+    pub const LEN: usize = 9 * 8 + 2 + 33 * 3 + (1 + 8 * STAKE_TIER_NUM) * 2;
+
+    /// Whether the update changes fees or owners, which must be queued when the timelock is enabled.
+    /// `disable_create_pool` and `mint_policy` are protective switches and stay immediate.
+    pub fn is_timelocked(&self) -> bool {
+        self.trade_fee_rate.is_some()
+            || self.protocol_fee_rate.is_some()
+            || self.fund_fee_rate.is_some()
+            || self.creator_fee_rate.is_some()
+            || self.create_pool_fee.is_some()
+            || self.protocol_owner.is_some()
+            || self.fund_owner.is_some()
+            || self.fee_token_mint.is_some()
+            || self.fee_token_discount_rate.is_some()
+            || self.referral_fee_rate.is_some()
+            || self.stake_tier_thresholds.is_some()
+            || self.stake_tier_discount_rates.is_some()
+    }
+}
+
+/// The updatable fields of the amm config
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AmmConfigSnapshot {
+    pub trade_fee_rate: u64,
+    pub protocol_fee_rate: u64,
+    pub fund_fee_rate: u64,
+    pub creator_fee_rate: u64,
+    pub create_pool_fee: u64,
+    pub disable_create_pool: bool,
+    pub protocol_owner: Pubkey,
+    pub fund_owner: Pubkey,
+    pub fee_token_mint: Pubkey,
+    pub fee_token_discount_rate: u64,
+    pub mint_policy: u64,
+    pub referral_fee_rate: u64,
+    pub stake_tier_thresholds: [u64; STAKE_TIER_NUM],
+    pub stake_tier_discount_rates: [u64; STAKE_TIER_NUM],
+}
+
+#[cfg(test)]
+pub mod config_test {
+    use super::*;
+
+    #[test]
+    fn amm_config_update_test() {
+        let mut amm_config = AmmConfig {
+            trade_fee_rate: 2500,
+            protocol_fee_rate: 120000,
+            fund_fee_rate: 40000,
+            ..Default::default()
+        };
+        let old = amm_config.snapshot();
+        amm_config
+            .update(&AmmConfigUpdate {
+                trade_fee_rate: Some(3000),
+                fee_token_discount_rate: Some(2000),
+                ..Default::default()
+            })
+            .unwrap();
+        let new = amm_config.snapshot();
+        assert_eq!(new.trade_fee_rate, 3000);
+        assert_eq!(new.fee_token_discount_rate, 2000);
+        assert_eq!(new.protocol_fee_rate, old.protocol_fee_rate);
+
+        assert!(amm_config
+            .update(&AmmConfigUpdate {
+                protocol_fee_rate: Some(FEE_RATE_DENOMINATOR_VALUE),
+                ..Default::default()
+            })
+            .is_err());
+        assert!(amm_config
+            .update(&AmmConfigUpdate {
+                fund_owner: Some(Pubkey::default()),
+                ..Default::default()
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn stake_tier_test() {
+        let mut amm_config = AmmConfig {
+            trade_fee_rate: 2500,
+            stake_tier_thresholds: [1000, 10000, 0],
+            stake_tier_discount_rates: [1000, 2000, 0],
+            ..Default::default()
+        };
+        amm_config.validate().unwrap();
+        assert_eq!(amm_config.get_staker_trade_fee_rate(0), 2500);
+        assert_eq!(amm_config.get_staker_trade_fee_rate(999), 2500);
+        assert_eq!(amm_config.get_staker_trade_fee_rate(1000), 2250);
+        assert_eq!(amm_config.get_staker_trade_fee_rate(u64::MAX), 2000);
+
+        amm_config.stake_tier_thresholds = [1000, 1000, 0];
+        assert!(amm_config.validate().is_err());
+        // a tier after a disabled tier
+        amm_config.stake_tier_thresholds = [1000, 0, 2000];
+        amm_config.stake_tier_discount_rates = [1000, 0, 2000];
+        assert!(amm_config.validate().is_err());
+        amm_config.stake_tier_thresholds = [0, 1000, 0];
+        assert!(amm_config.validate().is_err());
+        // a higher tier with a lower discount
+        amm_config.stake_tier_thresholds = [1000, 10000, 0];
+        amm_config.stake_tier_discount_rates = [2000, 1000, 0];
+        assert!(amm_config.validate().is_err());
+        amm_config.stake_tier_discount_rates = [1000, 1000, 0];
+        amm_config.validate().unwrap();
+        amm_config.stake_tier_discount_rates = [1000, FEE_TOKEN_DISCOUNT_RATE_DENOMINATOR + 1, 0];
+        assert!(amm_config.validate().is_err());
+
+        amm_config
+            .update(&AmmConfigUpdate {
+                stake_tier_thresholds: Some([1000, 10000, 100000]),
+                stake_tier_discount_rates: Some([1000, 2000, 3000]),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(amm_config.get_staker_trade_fee_rate(u64::MAX), 1750);
+    }
+
+    #[test]
+    fn amm_config_update_len_test() {
+        let params = AmmConfigUpdate {
+            trade_fee_rate: Some(0),
+            protocol_fee_rate: Some(0),
+            fund_fee_rate: Some(0),
+            creator_fee_rate: Some(0),
+            create_pool_fee: Some(0),
+            disable_create_pool: Some(true),
+            protocol_owner: Some(Pubkey::default()),
+            fund_owner: Some(Pubkey::default()),
+            fee_token_mint: Some(Pubkey::default()),
+            fee_token_discount_rate: Some(0),
+            mint_policy: Some(0),
+            referral_fee_rate: Some(0),
+            stake_tier_thresholds: Some([0; STAKE_TIER_NUM]),
+            stake_tier_discount_rates: Some([0; STAKE_TIER_NUM]),
+        };
+        assert_eq!(params.try_to_vec().unwrap().len(), AmmConfigUpdate::LEN);
+        assert!(params.is_timelocked());
+
+        let params = AmmConfigUpdate {
+            disable_create_pool: Some(true),
+            mint_policy: Some(1),
+            ..Default::default()
+        };
+        assert!(!params.is_timelocked());
+    }
 }
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+pub const DCA_VAULT_SEED: &str = "dca_vault";
+pub const DCA_TOKEN_VAULT_SEED: &str = "dca_token_vault";
+/// The seconds of observations the execution price of a DCA vault is compared against
+pub const DCA_TWAP_WINDOW: u64 = 5 * 60;
+
+/// A vault buying the output token with a fixed amount of the input token every period
+#[account]
+#[derive(Default, Debug)]
+pub struct DcaVault {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The owner of the vault
+    pub owner: Pubkey,
+    /// The pool the vault swaps through
+    pub pool_id: Pubkey,
+    /// The mint of the token sold
+    pub input_mint: Pubkey,
+    /// The mint of the token bought
+    pub output_mint: Pubkey,
+    /// The owner chosen nonce, allows many vaults per owner and pool
+    pub nonce: u64,
+    /// The amount of input tokens sold every period
+    pub amount_per_period: u64,
+    /// The seconds between executions
+    pub period: u64,
+    /// The max deviation of the execution price below the twap of the input token,
+    /// denominated in basis points (10000 = 100%). The execution price includes the trade fee,
+    /// so the deviation exceeds the trade fee rate
+    pub max_twap_deviation_bps: u64,
+    /// The input tokens left to sell, excluding transfer fees
+    pub input_amount: u64,
+    /// The output tokens bought, held by the token vault
+    pub output_amount: u64,
+    /// The timestamp of the next execution
+    pub next_execution_time: u64,
+    /// padding
+    pub padding: [u64; 8],
+}
+
+impl DcaVault {
+    pub const LEN: usize = 8 + 1 + 32 * 4 + 8 * 7 + 8 * 8;
+
+    /// The amount of input tokens sold by the next execution
+    pub fn amount_due(&self, block_timestamp: u64) -> u64 {
+        if block_timestamp < self.next_execution_time {
+            return 0;
+        }
+        self.amount_per_period.min(self.input_amount)
+    }
+
+    /// Schedule the next execution on the cadence, skipping the periods missed by the crank
+    pub fn advance(&mut self, block_timestamp: u64) {
+        let missed_periods = block_timestamp.saturating_sub(self.next_execution_time) / self.period;
+        self.next_execution_time += (missed_periods + 1) * self.period;
+    }
+}
+
+#[cfg(test)]
+pub mod dca_test {
+    use super::*;
+
+    #[test]
+    fn dca_vault_size_test() {
+        assert_eq!(
+            DcaVault::default().try_to_vec().unwrap().len(),
+            DcaVault::LEN - 8
+        );
+    }
+
+    #[test]
+    fn dca_vault_schedule_test() {
+        let mut dca_vault = DcaVault {
+            amount_per_period: 100,
+            period: 60,
+            input_amount: 250,
+            next_execution_time: 1000,
+            ..Default::default()
+        };
+        assert_eq!(dca_vault.amount_due(999), 0);
+        assert_eq!(dca_vault.amount_due(1000), 100);
+        dca_vault.advance(1010);
+        assert_eq!(dca_vault.next_execution_time, 1060);
+        // two periods missed
+        dca_vault.advance(1190);
+        assert_eq!(dca_vault.next_execution_time, 1240);
+
+        dca_vault.input_amount = 50;
+        assert_eq!(dca_vault.amount_due(1240), 50);
+    }
+}
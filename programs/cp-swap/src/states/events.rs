@@ -1,3 +1,4 @@
+use crate::states::{AmmConfigSnapshot, AmmConfigUpdate, FeeRecipient, STAKE_TIER_NUM};
 use anchor_lang::prelude::*;
 
 /// Emitted when deposit and withdraw
@@ -42,4 +43,296 @@ pub struct SwapEvent {
     /// Amount of fee tokens going to creator
     pub creator_fee: u64,
     pub creator_fee_on_input: bool,
+    /// input_amount in ui amount space, equals input_amount if the input mint is not scaled
+    pub input_ui_amount: u64,
+    /// output_amount in ui amount space, equals output_amount if the output mint is not scaled
+    pub output_ui_amount: u64,
+    /// Amount of input tokens paid to the referrer out of the protocol fee
+    pub referral_fee: u64,
+}
+
+/// Emitted when the amm config is updated
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct ConfigUpdatedEvent {
+    pub amm_config: Pubkey,
+    pub old: AmmConfigSnapshot,
+    pub new: AmmConfigSnapshot,
+}
+
+/// Emitted when a mint is added to or removed from the allowlist
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct MintAllowlistEvent {
+    pub mint: Pubkey,
+    /// true: added, false: removed
+    pub allowed: bool,
+}
+
+/// Emitted when the admin transfer is proposed or accepted
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct AdminTransferEvent {
+    /// The admin before the transfer
+    pub admin: Pubkey,
+    /// The proposed admin, default pubkey when the proposal is cancelled
+    pub pending_admin: Pubkey,
+    /// false: proposed, true: accepted
+    pub accepted: bool,
+}
+
+/// Emitted when a timelocked config update is queued, executed or cancelled
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct QueuedConfigUpdateEvent {
+    pub amm_config: Pubkey,
+    pub params: AmmConfigUpdate,
+    pub executable_at: u64,
+    // 0: queue, 1: execute, 2: cancel
+    pub change_type: u8,
+}
+
+/// Emitted when the timelock delay is updated
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct TimelockDelayUpdatedEvent {
+    pub old_delay: u64,
+    pub new_delay: u64,
+}
+
+/// Emitted when the guardian is updated
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct GuardianUpdatedEvent {
+    pub old_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+}
+
+/// Emitted when the guardian disables a pool
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct PoolPausedEvent {
+    pub pool_id: Pubkey,
+    /// The disabled bits
+    pub disabled_status: u8,
+    /// The pool status after the pause
+    pub status: u8,
+}
+
+/// Emitted when the protocol-wide status is updated
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct ProtocolStatusUpdatedEvent {
+    pub old_status: u8,
+    pub new_status: u8,
+}
+
+/// Emitted when the pool creator transfer is proposed or accepted
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct PoolCreatorTransferEvent {
+    pub pool_id: Pubkey,
+    /// The pool creator before the transfer
+    pub pool_creator: Pubkey,
+    /// The proposed pool creator, default pubkey when the proposal is cancelled
+    pub pending_pool_creator: Pubkey,
+    /// false: proposed, true: accepted
+    pub accepted: bool,
+}
+
+/// Emitted when the creator fee recipient of a pool is updated
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct CreatorFeeRecipientUpdatedEvent {
+    pub pool_id: Pubkey,
+    pub old_recipient: Pubkey,
+    pub new_recipient: Pubkey,
+}
+
+/// Emitted when the creator fee switch or mode of a pool is updated
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct CreatorFeeUpdatedEvent {
+    pub pool_id: Pubkey,
+    pub old_enable_creator_fee: bool,
+    pub new_enable_creator_fee: bool,
+    /// 0: both token, 1: only token_0, 2: only token_1
+    pub old_creator_fee_on: u8,
+    pub new_creator_fee_on: u8,
+}
+
+/// Emitted when the fee distribution of an amm config is set
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct FeeDistributionUpdatedEvent {
+    pub amm_config: Pubkey,
+    /// 0: protocol fees, 1: fund fees
+    pub fee_kind: u8,
+    pub recipients: Vec<FeeRecipient>,
+}
+
+/// Emitted when the protocol or fund fees of a pool are split between the recipients
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct FeeDistributedEvent {
+    pub pool_id: Pubkey,
+    /// 0: protocol fees, 1: fund fees
+    pub fee_kind: u8,
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
+/// Emitted when the protocol and fund fees of a pool are swept by `collect_fees_batch`
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct CollectFeesEvent {
+    pub pool_id: Pubkey,
+    pub protocol_fees_token_0: u64,
+    pub protocol_fees_token_1: u64,
+    pub fund_fees_token_0: u64,
+    pub fund_fees_token_1: u64,
+}
+
+/// Emitted when protocol fees are swapped for the K token
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct BuybackEvent {
+    /// The pool the protocol fees are taken from
+    pub pool_id: Pubkey,
+    /// The pool the protocol fees are swapped through
+    pub buyback_pool_id: Pubkey,
+    pub fee_mint: Pubkey,
+    pub amount_in: u64,
+    pub input_transfer_fee: u64,
+    /// The amount of K token bought back
+    pub buyback_amount: u64,
+    /// true: burned, false: sent to the buyback destination
+    pub burned: bool,
+}
+
+/// Emitted when the buyback config is set
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct BuybackConfigUpdatedEvent {
+    pub old_buyback_pool: Pubkey,
+    pub new_buyback_pool: Pubkey,
+    pub old_buyback_mint: Pubkey,
+    pub new_buyback_mint: Pubkey,
+    pub old_buyback_destination: Pubkey,
+    pub new_buyback_destination: Pubkey,
+}
+
+/// Emitted when K tokens are staked or unstaked
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct StakeEvent {
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    /// The amount staked or unstaked
+    pub amount: u64,
+    /// The staked amount after the change
+    pub staked_amount: u64,
+    pub unlock_time: u64,
+    // 0: stake, 1: unstake
+    pub change_type: u8,
+}
+
+/// Emitted when the staking tiers of an amm config are updated
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct StakeTiersUpdatedEvent {
+    pub amm_config: Pubkey,
+    pub stake_tier_thresholds: [u64; STAKE_TIER_NUM],
+    pub stake_tier_discount_rates: [u64; STAKE_TIER_NUM],
+}
+
+/// Emitted when a limit order is placed, cancelled or filled
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct LimitOrderEvent {
+    pub order_id: Pubkey,
+    pub owner: Pubkey,
+    pub pool_id: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    /// The escrowed amount
+    pub amount_in: u64,
+    /// The amount received by the owner on fill
+    pub amount_out: u64,
+    pub target_price_x32: u128,
+    pub keeper_bounty: u64,
+    // 0: place, 1: cancel, 2: fill
+    pub change_type: u8,
+}
+
+/// Emitted when a long term order is placed, executed or closed
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct LongTermOrderEvent {
+    pub order_id: Pubkey,
+    pub owner: Pubkey,
+    pub pool_id: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    /// The amount deposited, sold by the execution or returned unsold on close
+    pub amount_in: u64,
+    /// The amount bought by the execution or paid out on close
+    pub amount_out: u64,
+    pub sold_amount: u64,
+    pub bought_amount: u64,
+    // 0: place, 1: execute, 2: close
+    pub change_type: u8,
+}
+
+/// Emitted when a DCA vault is opened, executed or closed
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct DcaVaultEvent {
+    pub dca_vault: Pubkey,
+    pub owner: Pubkey,
+    pub pool_id: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    /// The amount deposited, sold by the execution or returned unsold on close
+    pub amount_in: u64,
+    /// The amount bought by the execution or paid out on close
+    pub amount_out: u64,
+    /// The input tokens left to sell after the change
+    pub input_amount: u64,
+    /// The output tokens held by the vault after the change
+    pub output_amount: u64,
+    // 0: open, 1: execute, 2: close
+    pub change_type: u8,
+}
+
+/// Emitted when the circuit breaker of a pool is updated by the admin
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct CircuitBreakerUpdatedEvent {
+    pub pool_id: Pubkey,
+    pub max_deviation_bps: u16,
+    pub window: u64,
+    pub pause: bool,
+}
+
+/// Emitted when the circuit breaker of a pool pauses swaps or is reset by the admin
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct CircuitBreakerEvent {
+    pub pool_id: Pubkey,
+    /// The post swap token_0 price that tripped the circuit breaker
+    pub token_0_price_x32: u128,
+    /// The twap token_0 price the post swap price is compared against
+    pub twap_token_0_price_x32: u128,
+    // 0: trip, 1: reset
+    pub change_type: u8,
+}
+
+/// Emitted when the admin switches the price space of the pool observations,
+/// the observations are reset by the switch
+#[event]
+#[cfg_attr(feature = "client", derive(Debug))]
+pub struct PoolUiAmountPricingEvent {
+    pub pool_id: Pubkey,
+    pub ui_amount_pricing: bool,
 }
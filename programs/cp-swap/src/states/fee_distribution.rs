@@ -0,0 +1,122 @@
+use crate::error::ErrorCode;
+use anchor_lang::prelude::*;
+
+pub const FEE_DISTRIBUTION_SEED: &str = "fee_distribution";
+/// Max number of recipients of a fee distribution
+pub const MAX_FEE_RECIPIENTS: usize = 8;
+/// Denominator of `FeeRecipient::share_bps`
+pub const FEE_SHARE_DENOMINATOR: u64 = 10000;
+
+/// The fees a distribution splits
+pub enum FeeDistributionKind {
+    Protocol,
+    Fund,
+}
+
+/// A recipient of a fee distribution and its share
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeeRecipient {
+    /// The owner of the token accounts receiving the fees
+    pub owner: Pubkey,
+    /// The share of the fees, denominated in basis points (10000 = 100%)
+    pub share_bps: u16,
+}
+
+/// Splits the protocol or fund fees of the pools under an amm config between weighted recipients
+#[account]
+#[derive(Default, Debug)]
+pub struct FeeDistribution {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// 0: protocol fees, 1: fund fees
+    pub fee_kind: u8,
+    /// The number of valid items in `recipients`
+    pub recipient_count: u8,
+    /// The amm config whose pools' fees are distributed
+    pub amm_config: Pubkey,
+    /// The recipients of the fees
+    pub recipients: [FeeRecipient; MAX_FEE_RECIPIENTS],
+    /// padding
+    pub padding: [u64; 8],
+}
+
+impl FeeDistribution {
+    pub const LEN: usize = 8 + 1 + 1 + 1 + 32 + (32 + 2) * MAX_FEE_RECIPIENTS + 8 * 8;
+
+    pub fn set_recipients(&mut self, recipients: &[FeeRecipient]) -> Result<()> {
+        require!(
+            !recipients.is_empty() && recipients.len() <= MAX_FEE_RECIPIENTS,
+            ErrorCode::InvalidInput
+        );
+        let total_share_bps = recipients
+            .iter()
+            .map(|recipient| u64::from(recipient.share_bps))
+            .sum::<u64>();
+        require_eq!(
+            total_share_bps,
+            FEE_SHARE_DENOMINATOR,
+            ErrorCode::InvalidInput
+        );
+
+        self.recipient_count = recipients.len() as u8;
+        self.recipients = [FeeRecipient::default(); MAX_FEE_RECIPIENTS];
+        self.recipients[..recipients.len()].copy_from_slice(recipients);
+        Ok(())
+    }
+
+    pub fn recipients(&self) -> &[FeeRecipient] {
+        &self.recipients[..self.recipient_count as usize]
+    }
+
+    /// Split the amount by the recipient shares, the rounding dust goes to the last recipient
+    pub fn split_amount(&self, amount: u64) -> Vec<u64> {
+        let mut remaining = amount;
+        let recipients = self.recipients();
+        recipients
+            .iter()
+            .enumerate()
+            .map(|(i, recipient)| {
+                let share = if i == recipients.len() - 1 {
+                    remaining
+                } else {
+                    (u128::from(amount) * u128::from(recipient.share_bps)
+                        / u128::from(FEE_SHARE_DENOMINATOR)) as u64
+                };
+                remaining -= share;
+                share
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+pub mod fee_distribution_test {
+    use super::*;
+
+    #[test]
+    fn fee_distribution_size_test() {
+        assert_eq!(
+            FeeDistribution::default().try_to_vec().unwrap().len(),
+            FeeDistribution::LEN - 8
+        )
+    }
+
+    #[test]
+    fn split_amount_test() {
+        let mut fee_distribution = FeeDistribution::default();
+        let recipient = |share_bps| FeeRecipient {
+            owner: Pubkey::new_unique(),
+            share_bps,
+        };
+        fee_distribution
+            .set_recipients(&[recipient(5000), recipient(3333), recipient(1667)])
+            .unwrap();
+        assert_eq!(fee_distribution.split_amount(10001), vec![5000, 3333, 1668]);
+        assert_eq!(fee_distribution.split_amount(0), vec![0, 0, 0]);
+
+        assert!(fee_distribution
+            .set_recipients(&[recipient(5000), recipient(4999)])
+            .is_err());
+        assert!(fee_distribution.set_recipients(&[]).is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+pub const LIMIT_ORDER_SEED: &str = "limit_order";
+pub const LIMIT_ORDER_VAULT_SEED: &str = "limit_order_vault";
+
+/// An order resting against a pool, filled once the swap gets the target price for the input token
+#[account]
+#[derive(Default, Debug)]
+pub struct LimitOrder {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The owner of the order
+    pub owner: Pubkey,
+    /// The pool the order is filled against
+    pub pool_id: Pubkey,
+    /// The mint of the escrowed token
+    pub input_mint: Pubkey,
+    /// The mint of the token received on fill
+    pub output_mint: Pubkey,
+    /// The owner chosen nonce, allows many orders per owner and pool
+    pub nonce: u64,
+    /// The escrowed amount, excluding transfer fees
+    pub amount_in: u64,
+    /// The minimum output amount received on fill, excluding transfer fees
+    pub minimum_amount_out: u64,
+    /// The price of the input token denominated in the output token the fill has to get, Q32.32
+    pub target_price_x32: u128,
+    /// The lamports paid to the keeper filling the order
+    pub keeper_bounty: u64,
+    /// The timestamp the order was placed
+    pub open_time: u64,
+    /// padding
+    pub padding: [u64; 8],
+}
+
+impl LimitOrder {
+    pub const LEN: usize = 8 + 1 + 32 * 4 + 8 * 3 + 16 + 8 * 2 + 8 * 8;
+
+    /// Whether swapping `amount_swapped` into the pool for `amount_received` gets the target price,
+    /// the price impact of the order itself included
+    pub fn is_fillable(&self, amount_swapped: u64, amount_received: u64) -> bool {
+        self.target_price_x32
+            .checked_mul(u128::from(amount_swapped))
+            .is_some_and(|min_received_x32| u128::from(amount_received) << 32 >= min_received_x32)
+    }
+}
+
+#[cfg(test)]
+pub mod limit_order_test {
+    use super::*;
+    use crate::curve::calculator::CurveCalculator;
+
+    #[test]
+    fn limit_order_size_test() {
+        assert_eq!(
+            LimitOrder::default().try_to_vec().unwrap().len(),
+            LimitOrder::LEN - 8
+        );
+    }
+
+    #[test]
+    fn is_fillable_test() {
+        let order = LimitOrder {
+            target_price_x32: 2 << 32,
+            ..Default::default()
+        };
+        assert!(!order.is_fillable(100, 199));
+        assert!(order.is_fillable(100, 200));
+        assert!(order.is_fillable(100, 300));
+        assert!(!order.is_fillable(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn is_fillable_price_impact_test() {
+        // the spot price of the input token is 2.0, the target price
+        let (input_vault_amount, output_vault_amount) = (1_000_000u64, 2_000_000u64);
+        let order = LimitOrder {
+            target_price_x32: (u128::from(output_vault_amount) << 32)
+                / u128::from(input_vault_amount),
+            ..Default::default()
+        };
+        let swap = |amount_in: u64| {
+            CurveCalculator::swap_base_input(
+                u128::from(amount_in),
+                u128::from(input_vault_amount),
+                u128::from(output_vault_amount),
+                0,
+                0,
+                0,
+                0,
+                false,
+            )
+            .unwrap()
+            .output_amount as u64
+        };
+        // the price impact of the order puts the execution price below the target
+        assert!(!order.is_fillable(1, swap(1)));
+        assert!(!order.is_fillable(100_000, swap(100_000)));
+        // a lower target is met by the execution price
+        let order = LimitOrder {
+            target_price_x32: order.target_price_x32 * 9 / 10,
+            ..order
+        };
+        assert!(order.is_fillable(100_000, swap(100_000)));
+        assert!(!order.is_fillable(500_000, swap(500_000)));
+    }
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+pub const LONG_TERM_ORDER_SEED: &str = "long_term_order";
+pub const LONG_TERM_ORDER_VAULT_SEED: &str = "long_term_order_vault";
+/// The seconds of observations the execution price of a long term order is compared against
+pub const LONG_TERM_ORDER_TWAP_WINDOW: u64 = 5 * 60;
+
+/// An order selling its input tokens at a constant rate between the start and end time.
+/// The order is only executed by `execute_long_term_order`, a permissionless crank, the swaps
+/// of other users through the pool do not execute it. Each execution sells at most
+/// `max_amount_per_execution` and is rejected when the execution price falls below the twap
+/// of the input token by more than `max_twap_deviation_bps`
+#[account]
+#[derive(Default, Debug)]
+pub struct LongTermOrder {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The owner of the order
+    pub owner: Pubkey,
+    /// The pool the order is executed against
+    pub pool_id: Pubkey,
+    /// The mint of the escrowed token
+    pub input_mint: Pubkey,
+    /// The mint of the token bought
+    pub output_mint: Pubkey,
+    /// The owner chosen nonce, allows many orders per owner and pool
+    pub nonce: u64,
+    /// The escrowed amount, excluding transfer fees
+    pub deposit_amount: u64,
+    /// The amount of input tokens sold so far
+    pub sold_amount: u64,
+    /// The amount of output tokens bought so far, held by the order vault
+    pub bought_amount: u64,
+    /// The timestamp selling starts
+    pub start_time: u64,
+    /// The timestamp all of the deposit is sold
+    pub end_time: u64,
+    /// The timestamp of the latest execution
+    pub last_execution_time: u64,
+    /// The max amount of input tokens sold by one execution
+    pub max_amount_per_execution: u64,
+    /// The max deviation of the execution price below the twap of the input token,
+    /// denominated in basis points (10000 = 100%)
+    pub max_twap_deviation_bps: u64,
+    /// padding
+    pub padding: [u64; 6],
+}
+
+impl LongTermOrder {
+    pub const LEN: usize = 8 + 1 + 32 * 4 + 8 * 9 + 8 * 6;
+
+    /// The amount of input tokens due to be sold at `block_timestamp`, following a linear schedule
+    pub fn amount_due(&self, block_timestamp: u64) -> u64 {
+        if block_timestamp <= self.start_time || self.end_time <= self.start_time {
+            return 0;
+        }
+        let elapsed = block_timestamp.min(self.end_time) - self.start_time;
+        let scheduled = u128::from(self.deposit_amount) * u128::from(elapsed)
+            / u128::from(self.end_time - self.start_time);
+        u64::try_from(scheduled)
+            .unwrap()
+            .saturating_sub(self.sold_amount)
+    }
+
+    /// The amount of input tokens sold by an execution at `block_timestamp`,
+    /// the amount due capped by `max_amount_per_execution`
+    pub fn execution_amount(&self, block_timestamp: u64) -> u64 {
+        self.amount_due(block_timestamp)
+            .min(self.max_amount_per_execution)
+    }
+}
+
+#[cfg(test)]
+pub mod long_term_order_test {
+    use super::*;
+
+    #[test]
+    fn long_term_order_size_test() {
+        assert_eq!(
+            LongTermOrder::default().try_to_vec().unwrap().len(),
+            LongTermOrder::LEN - 8
+        );
+    }
+
+    #[test]
+    fn amount_due_test() {
+        let mut order = LongTermOrder {
+            deposit_amount: 1000,
+            start_time: 100,
+            end_time: 200,
+            ..Default::default()
+        };
+        assert_eq!(order.amount_due(100), 0);
+        assert_eq!(order.amount_due(150), 500);
+        order.sold_amount = 500;
+        assert_eq!(order.amount_due(150), 0);
+        assert_eq!(order.amount_due(175), 250);
+        assert_eq!(order.amount_due(300), 500);
+        order.sold_amount = 1000;
+        assert_eq!(order.amount_due(300), 0);
+    }
+
+    #[test]
+    fn execution_amount_test() {
+        let mut order = LongTermOrder {
+            deposit_amount: 1000,
+            start_time: 100,
+            end_time: 200,
+            max_amount_per_execution: 300,
+            ..Default::default()
+        };
+        assert_eq!(order.execution_amount(150), 300);
+        order.sold_amount = 300;
+        assert_eq!(order.execution_amount(150), 200);
+        // the amount left due is sold by the next executions
+        assert_eq!(order.execution_amount(200), 300);
+        order.sold_amount = 900;
+        assert_eq!(order.execution_amount(200), 100);
+    }
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+pub const MINT_ALLOWLIST_SEED: &str = "mint_allowlist";
+
+/// Marks a token-2022 mint as allowed by the admin.
+/// Allowed mints can be used in pools regardless of their extensions.
+#[account]
+#[derive(Default, Debug)]
+pub struct MintAllowlistEntry {
+    /// The allowed mint
+    pub mint: Pubkey,
+    /// padding
+    pub padding: [u64; 8],
+}
+
+impl MintAllowlistEntry {
+    pub const LEN: usize = 8 + 32 + 8 * 8;
+}
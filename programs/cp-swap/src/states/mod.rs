@@ -12,3 +12,33 @@ pub use oracle::*;
 
 pub mod permission;
 pub use permission::*;
+
+pub mod transfer_hook;
+pub use transfer_hook::*;
+
+pub mod mint_allowlist;
+pub use mint_allowlist::*;
+
+pub mod protocol;
+pub use protocol::*;
+
+pub mod timelock;
+pub use timelock::*;
+
+pub mod fee_distribution;
+pub use fee_distribution::*;
+
+pub mod stake;
+pub use stake::*;
+
+pub mod return_data;
+pub use return_data::*;
+
+pub mod limit_order;
+pub use limit_order::*;
+
+pub mod long_term_order;
+pub use long_term_order::*;
+
+pub mod dca;
+pub use dca::*;
@@ -17,6 +17,7 @@ pub struct Observation {
     /// The block timestamp of the observation
     pub block_timestamp: u64,
     /// the cumulative of token0 price during the duration time, Q32.32, the remaining 64 bit for overflow
+    /// the price is in ui amount space when the pool enables `ui_amount_pricing`
     pub cumulative_token_0_price_x32: u128,
     /// the cumulative of token1 price during the duration time, Q32.32, the remaining 64 bit for overflow
     pub cumulative_token_1_price_x32: u128,
@@ -36,8 +37,12 @@ pub struct ObservationState {
     pub pool_id: Pubkey,
     /// observation array
     pub observations: [Observation; OBSERVATION_NUM],
+    /// ui amount multiplier of token_0 at the last update, Q32.32, ui_amount = amount * multiplier / 2^32
+    pub token_0_ui_multiplier_x32: u64,
+    /// ui amount multiplier of token_1 at the last update, Q32.32, ui_amount = amount * multiplier / 2^32
+    pub token_1_ui_multiplier_x32: u64,
     /// padding for feature update
-    pub padding: [u64; 4],
+    pub padding: [u64; 2],
 }
 
 impl Default for ObservationState {
@@ -48,13 +53,15 @@ impl Default for ObservationState {
             observation_index: 0,
             pool_id: Pubkey::default(),
             observations: [Observation::default(); OBSERVATION_NUM],
-            padding: [0u64; 4],
+            token_0_ui_multiplier_x32: 0,
+            token_1_ui_multiplier_x32: 0,
+            padding: [0u64; 2],
         }
     }
 }
 
 impl ObservationState {
-    pub const LEN: usize = 8 + 1 + 2 + 32 + (Observation::LEN * OBSERVATION_NUM) + 8 * 4;
+    pub const LEN: usize = 8 + 1 + 2 + 32 + (Observation::LEN * OBSERVATION_NUM) + 8 * 2 + 8 * 2;
 
     // Writes an oracle observation to the account, returning the next observation_index.
     /// Writable at most once per second. Index represents the most recently written element.
@@ -110,6 +117,77 @@ impl ObservationState {
             self.observation_index = next_observation_index;
         }
     }
+
+    /// Drops all observations, the next update starts a new price series.
+    /// Used when the price space of the observations changes
+    pub fn reset(&mut self) {
+        self.initialized = false;
+        self.observation_index = 0;
+        self.observations = [Observation::default(); OBSERVATION_NUM];
+        self.token_0_ui_multiplier_x32 = 0;
+        self.token_1_ui_multiplier_x32 = 0;
+    }
+
+    /// Records the latest ui amount multipliers of the pool tokens,
+    /// used to convert raw amounts to the ui amount space of the observed prices
+    pub fn update_ui_amount_multiplier(
+        &mut self,
+        token_0_ui_multiplier_x32: u64,
+        token_1_ui_multiplier_x32: u64,
+    ) {
+        self.token_0_ui_multiplier_x32 = token_0_ui_multiplier_x32;
+        self.token_1_ui_multiplier_x32 = token_1_ui_multiplier_x32;
+    }
+
+    /// Returns the time weighted average prices of token_0 and token_1, Q32.32, over at least the `window`
+    /// seconds before `block_timestamp`, None when the observations do not cover the window.
+    /// The prices since the latest observation are the current prices of the pool, `token_0_price_x32`
+    /// and `token_1_price_x32`, in the price space of the observations
+    pub fn twap_price_x32(
+        &self,
+        window: u64,
+        block_timestamp: u64,
+        token_0_price_x32: u128,
+        token_1_price_x32: u128,
+    ) -> Option<(u128, u128)> {
+        if !self.initialized || window == 0 {
+            return None;
+        }
+        let latest = self.observations[self.observation_index as usize];
+        let elapsed = u128::from(block_timestamp.checked_sub(latest.block_timestamp)?);
+        let cumulative_token_0_price_x32 = latest
+            .cumulative_token_0_price_x32
+            .wrapping_add(token_0_price_x32.checked_mul(elapsed)?);
+        let cumulative_token_1_price_x32 = latest
+            .cumulative_token_1_price_x32
+            .wrapping_add(token_1_price_x32.checked_mul(elapsed)?);
+        let mut index = self.observation_index as usize;
+        for _ in 0..OBSERVATION_NUM {
+            let observation = self.observations[index];
+            if observation.block_timestamp == 0
+                || observation.block_timestamp > latest.block_timestamp
+            {
+                return None;
+            }
+            let delta_time = block_timestamp - observation.block_timestamp;
+            if delta_time >= window {
+                return Some((
+                    cumulative_token_0_price_x32
+                        .wrapping_sub(observation.cumulative_token_0_price_x32)
+                        / u128::from(delta_time),
+                    cumulative_token_1_price_x32
+                        .wrapping_sub(observation.cumulative_token_1_price_x32)
+                        / u128::from(delta_time),
+                ));
+            }
+            index = if index == 0 {
+                OBSERVATION_NUM - 1
+            } else {
+                index - 1
+            };
+        }
+        None
+    }
 }
 
 /// Returns the block timestamp truncated to 32 bits, i.e. mod 2**32
@@ -137,4 +215,66 @@ pub mod observation_test {
             ObservationState::LEN - 8
         )
     }
+
+    #[test]
+    fn twap_price_x32_test() {
+        let mut observation_state = ObservationState::default();
+        let start = 1_000_000;
+        assert_eq!(observation_state.twap_price_x32(60, start, 0, 0), None);
+
+        observation_state.update(start, 0, 0);
+        for i in 1..=4 {
+            observation_state.update(start + i * 30, (i as u128) << 32, 1 << 32);
+        }
+        let now = start + 120;
+        // (3 + 4) * 30 / 60
+        assert_eq!(
+            observation_state.twap_price_x32(60, now, 5 << 32, 1 << 32),
+            Some((7 << 31, 1 << 32))
+        );
+        // (1 + 2 + 3 + 4) * 30 / 120
+        assert_eq!(
+            observation_state.twap_price_x32(100, now, 5 << 32, 1 << 32),
+            Some((10 << 30, 1 << 32))
+        );
+        assert_eq!(
+            observation_state.twap_price_x32(121, now, 5 << 32, 1 << 32),
+            None
+        );
+        // before the latest observation
+        assert_eq!(
+            observation_state.twap_price_x32(60, now - 1, 5 << 32, 1 << 32),
+            None
+        );
+
+        // the window ends at the block timestamp, the current price fills the time since the latest observation
+        let now = start + 150;
+        // (4 + 5) * 30 / 60
+        assert_eq!(
+            observation_state.twap_price_x32(60, now, 5 << 32, 1 << 32),
+            Some((9 << 31, 1 << 32))
+        );
+        assert_eq!(
+            observation_state.twap_price_x32(30, now, 5 << 32, 1 << 32),
+            Some((5 << 32, 1 << 32))
+        );
+        // (1 + 2 + 3 + 4 + 5) * 30 / 150
+        assert_eq!(
+            observation_state.twap_price_x32(150, now, 5 << 32, 1 << 32),
+            Some((3 << 32, 1 << 32))
+        );
+
+        // a reset drops the prices recorded before it
+        observation_state.reset();
+        assert_eq!(
+            observation_state.twap_price_x32(60, now, 5 << 32, 1 << 32),
+            None
+        );
+        observation_state.update(start + 200, 0, 0);
+        observation_state.update(start + 260, 5 << 32, 1 << 32);
+        assert_eq!(
+            observation_state.twap_price_x32(60, start + 260, 0, 0),
+            Some((5 << 32, 1 << 32))
+        );
+    }
 }
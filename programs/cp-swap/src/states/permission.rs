@@ -1,6 +1,18 @@
+use crate::error::ErrorCode;
 use anchor_lang::prelude::*;
+use std::ops::BitAnd;
 
 pub const PERMISSION_SEED: &str = "permission";
+/// Max number of config indexes a permission can be scoped to
+pub const MAX_PERMISSION_CONFIG_INDEXES: usize = 8;
+/// All bits of `PermissionPrivilegeBitIndex`
+pub const PERMISSION_PRIVILEGE_MASK: u8 = 0b11;
+
+/// Privileged pool fields that can be rejected for a permission
+pub enum PermissionPrivilegeBitIndex {
+    EnableCreatorFee,
+    CreatorFeeOn,
+}
 
 /// Holds the current owner of the factory
 #[account]
@@ -8,10 +20,132 @@ pub const PERMISSION_SEED: &str = "permission";
 pub struct Permission {
     /// authority
     pub authority: Pubkey,
+    /// The timestamp after which the permission can not be used, 0 means never expires
+    pub expire_time: u64,
+    /// The max number of pools the permission can create, 0 means unlimited
+    pub max_pool_count: u64,
+    /// The number of pools created with the permission
+    pub pool_count: u64,
+    /// Bitwise representation of the privileged fields rejected for the permission
+    /// bit0, 1: can not enable the creator fee(value is 1), 0: allow
+    /// bit1, 1: can only use `CreatorFeeOn::BothToken`(value is 2), 0: allow
+    pub privilege_status: u8,
+    /// The number of valid items in `allowed_config_indexes`, 0 means any config
+    pub allowed_config_index_count: u8,
+    /// The indexes of the configs the permission can create pools under
+    pub allowed_config_indexes: [u16; MAX_PERMISSION_CONFIG_INDEXES],
     /// padding
-    pub padding: [u64; 30],
+    pub padding1: [u8; 6],
+    /// padding
+    pub padding: [u64; 24],
 }
 
 impl Permission {
-    pub const LEN: usize = 8 + 32 + 8 * 30;
+    pub const LEN: usize = 8 + 32 + 8 * 3 + 1 + 1 + 2 * MAX_PERMISSION_CONFIG_INDEXES + 6 + 8 * 24;
+
+    pub fn set_scope(&mut self, scope: &PermissionScope) -> Result<()> {
+        require!(
+            scope.allowed_config_indexes.len() <= MAX_PERMISSION_CONFIG_INDEXES,
+            ErrorCode::InvalidInput
+        );
+        require!(
+            scope.privilege_status & !PERMISSION_PRIVILEGE_MASK == 0,
+            ErrorCode::InvalidInput
+        );
+        self.expire_time = scope.expire_time;
+        self.max_pool_count = scope.max_pool_count;
+        self.privilege_status = scope.privilege_status;
+        self.allowed_config_index_count = scope.allowed_config_indexes.len() as u8;
+        self.allowed_config_indexes = [0u16; MAX_PERMISSION_CONFIG_INDEXES];
+        self.allowed_config_indexes[..scope.allowed_config_indexes.len()]
+            .copy_from_slice(&scope.allowed_config_indexes);
+        Ok(())
+    }
+
+    /// Get privilege by bit, if the privileged field can be set, return true
+    pub fn get_privilege_by_bit(&self, bit: PermissionPrivilegeBitIndex) -> bool {
+        let privilege = 1u8 << (bit as u8);
+        self.privilege_status.bitand(privilege) == 0
+    }
+
+    /// Check the permission can create another pool under the config
+    pub fn check_create_pool(&self, config_index: u16, block_timestamp: u64) -> Result<()> {
+        require!(
+            self.expire_time == 0 || block_timestamp <= self.expire_time,
+            ErrorCode::PermissionExpired
+        );
+        require!(
+            self.max_pool_count == 0 || self.pool_count < self.max_pool_count,
+            ErrorCode::PermissionPoolLimitReached
+        );
+        require!(
+            self.allowed_config_index_count == 0
+                || self.allowed_config_indexes[..self.allowed_config_index_count as usize]
+                    .contains(&config_index),
+            ErrorCode::PermissionConfigNotAllowed
+        );
+        Ok(())
+    }
+}
+
+/// Scope of a permission, zero values keep the permission unrestricted
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct PermissionScope {
+    pub allowed_config_indexes: Vec<u16>,
+    pub expire_time: u64,
+    pub max_pool_count: u64,
+    pub privilege_status: u8,
+}
+
+#[cfg(test)]
+pub mod permission_test {
+    use super::*;
+
+    #[test]
+    fn permission_size_test() {
+        assert_eq!(
+            Permission::default().try_to_vec().unwrap().len(),
+            Permission::LEN - 8
+        )
+    }
+
+    #[test]
+    fn check_create_pool_test() {
+        let mut permission = Permission::default();
+        assert!(permission.check_create_pool(3, 100).is_ok());
+
+        permission
+            .set_scope(&PermissionScope {
+                allowed_config_indexes: vec![1, 2],
+                expire_time: 100,
+                max_pool_count: 1,
+                privilege_status: 1,
+            })
+            .unwrap();
+        assert!(permission.check_create_pool(2, 100).is_ok());
+        assert!(permission.check_create_pool(3, 100).is_err());
+        assert!(permission.check_create_pool(2, 101).is_err());
+        assert!(!permission.get_privilege_by_bit(PermissionPrivilegeBitIndex::EnableCreatorFee));
+        assert!(permission.get_privilege_by_bit(PermissionPrivilegeBitIndex::CreatorFeeOn));
+
+        permission.pool_count = 1;
+        assert!(permission.check_create_pool(2, 100).is_err());
+    }
+
+    #[test]
+    fn set_scope_test() {
+        let mut permission = Permission::default();
+        assert!(permission
+            .set_scope(&PermissionScope {
+                allowed_config_indexes: vec![0; MAX_PERMISSION_CONFIG_INDEXES + 1],
+                ..Default::default()
+            })
+            .is_err());
+        assert!(permission
+            .set_scope(&PermissionScope {
+                privilege_status: 4,
+                ..Default::default()
+            })
+            .is_err());
+    }
 }
@@ -1,4 +1,5 @@
-use crate::{curve::TradeDirection, error::ErrorCode};
+use super::{ObservationState, OBSERVATION_NUM, OBSERVATION_UPDATE_DURATION_DEFAULT};
+use crate::{curve::TradeDirection, error::ErrorCode, utils::is_ui_amount_scaled_mint};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 use std::ops::{BitAnd, BitOr, BitXor};
@@ -7,7 +8,18 @@ pub const POOL_SEED: &str = "pool";
 pub const POOL_LP_MINT_SEED: &str = "pool_lp_mint";
 pub const POOL_VAULT_SEED: &str = "pool_vault";
 
+pub const LP_MINT_DECIMALS: u8 = 9;
+/// Symbol of lp mints created with token metadata, the name is `KDLK-LP TOKEN0/TOKEN1`
+pub const LP_MINT_SYMBOL: &str = "KDLK-LP";
+pub const MAX_LP_MINT_TOKEN_SYMBOL_LEN: usize = 10;
+pub const MAX_LP_MINT_URI_LEN: usize = 200;
+
 pub const Q32: u128 = (u32::MAX as u128) + 1; // 2^32
+pub const CIRCUIT_BREAKER_BPS_DENOMINATOR: u64 = 10000;
+/// The max window of the circuit breaker, the seconds the observations are guaranteed to cover.
+/// A longer window finds no twap and the circuit breaker would never trip
+pub const MAX_CIRCUIT_BREAKER_WINDOW: u64 =
+    OBSERVATION_NUM as u64 * OBSERVATION_UPDATE_DURATION_DEFAULT;
 
 pub enum PoolStatusBitIndex {
     Deposit,
@@ -32,6 +44,39 @@ pub enum CreatorFeeOn {
     OnlyToken1,
 }
 
+/// Token metadata of the lp mint, the lp mint is created under token program 2022 when it is provided
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct LpMintMetadata {
+    /// Symbol of token_0, used to build the lp name
+    pub token_0_symbol: String,
+    /// Symbol of token_1, used to build the lp name
+    pub token_1_symbol: String,
+    /// Uri of the lp token metadata json
+    pub uri: String,
+}
+
+impl LpMintMetadata {
+    /// Returns the (name, symbol, uri) of the lp token metadata
+    pub fn to_token_metadata(&self) -> Result<(String, String, String)> {
+        require!(
+            !self.token_0_symbol.is_empty()
+                && !self.token_1_symbol.is_empty()
+                && self.token_0_symbol.len() <= MAX_LP_MINT_TOKEN_SYMBOL_LEN
+                && self.token_1_symbol.len() <= MAX_LP_MINT_TOKEN_SYMBOL_LEN
+                && self.uri.len() <= MAX_LP_MINT_URI_LEN,
+            ErrorCode::InvalidLpMintMetadata
+        );
+        Ok((
+            format!(
+                "{} {}/{}",
+                LP_MINT_SYMBOL, self.token_0_symbol, self.token_1_symbol
+            ),
+            LP_MINT_SYMBOL.to_string(),
+            self.uri.clone(),
+        ))
+    }
+}
+
 impl CreatorFeeOn {
     fn from_u8(value: u8) -> Result<Self> {
         match value {
@@ -121,19 +166,54 @@ pub struct PoolState {
     /// 2: only token_1 as trade fee
     pub creator_fee_on: u8,
     pub enable_creator_fee: bool,
-    pub padding1: [u8; 6],
+    /// Whether the observation records prices in ui amount space,
+    /// it is enabled when a mint scales its ui amount, e.g. interest bearing mints
+    pub ui_amount_pricing: bool,
+    /// Whether only the admin can change `creator_fee_on`,
+    /// set for pools created with a permission restricted to `CreatorFeeOn::BothToken`
+    pub creator_fee_on_locked: bool,
+    pub padding1: [u8; 4],
     pub creator_fees_token_0: u64,
     pub creator_fees_token_1: u64,
     /// Fee token vault for collecting protocol fee token payments
     pub fee_token_vault: Pubkey,
     /// Total collected fee token amount
     pub collected_fee_token_amount: u64,
+    /// The proposed pool creator, takes effect once accepted
+    pub pending_pool_creator: Pubkey,
+    /// The address receiving the creator fees, default pubkey means the pool creator
+    pub creator_fee_recipient: Pubkey,
+    /// The max deviation of the post swap price from the observation twap,
+    /// denominated in basis points (10000 = 100%), 0 means the circuit breaker is disabled
+    pub circuit_breaker_bps: u16,
+    /// true: pause swaps of the pool after a swap beyond the deviation, false: reject the swap
+    pub circuit_breaker_pause: bool,
+    /// Whether the circuit breaker paused swaps of the pool, cleared by the admin reset
+    pub circuit_breaker_tripped: bool,
+    pub padding2: [u8; 4],
+    /// The seconds of observations the post swap price is compared against
+    pub circuit_breaker_window: u64,
     /// padding for future updates
-    pub padding: [u64; 23],
+    pub padding: [u64; 13],
 }
 
 impl PoolState {
-    pub const LEN: usize = 8 + 10 * 32 + 1 * 5 + 8 * 7 + 1 * 2 + 6 * 1 + 2 * 8 + 32 + 8 + 8 * 23;
+    pub const LEN: usize = 8
+        + 10 * 32
+        + 1 * 5
+        + 8 * 7
+        + 1 * 4
+        + 4 * 1
+        + 2 * 8
+        + 32
+        + 8
+        + 32 * 2
+        + 2
+        + 1
+        + 1
+        + 4
+        + 8
+        + 8 * 13;
 
     pub fn initialize(
         &mut self,
@@ -175,10 +255,20 @@ impl PoolState {
         self.recent_epoch = Clock::get().unwrap().epoch;
         self.creator_fee_on = creator_fee_on.to_u8();
         self.enable_creator_fee = enable_creator_fee;
-        self.padding1 = [0u8; 6];
+        self.ui_amount_pricing = is_ui_amount_scaled_mint(&token_0_mint.to_account_info())
+            || is_ui_amount_scaled_mint(&token_1_mint.to_account_info());
+        self.creator_fee_on_locked = false;
+        self.padding1 = [0u8; 4];
         self.creator_fees_token_0 = 0;
         self.creator_fees_token_1 = 0;
-        self.padding = [0u64; 23];
+        self.pending_pool_creator = Pubkey::default();
+        self.creator_fee_recipient = Pubkey::default();
+        self.circuit_breaker_bps = 0;
+        self.circuit_breaker_pause = false;
+        self.circuit_breaker_tripped = false;
+        self.padding2 = [0u8; 4];
+        self.circuit_breaker_window = 0;
+        self.padding = [0u64; 13];
     }
 
     pub fn set_status(&mut self, status: u8) {
@@ -195,6 +285,22 @@ impl PoolState {
         }
     }
 
+    /// Update the creator fee switch and mode, fees already accrued in
+    /// `creator_fees_token_0/1` stay collectable by the creator
+    pub fn set_creator_fee(&mut self, enable_creator_fee: bool, creator_fee_on: CreatorFeeOn) {
+        self.enable_creator_fee = enable_creator_fee;
+        self.creator_fee_on = creator_fee_on.to_u8();
+    }
+
+    /// The address receiving the creator fees
+    pub fn get_creator_fee_recipient(&self) -> Pubkey {
+        if self.creator_fee_recipient == Pubkey::default() {
+            self.pool_creator
+        } else {
+            self.creator_fee_recipient
+        }
+    }
+
     /// Get status by bit, if it is `noraml` status, return true
     pub fn get_status_by_bit(&self, bit: PoolStatusBitIndex) -> bool {
         let status = u8::from(1) << (bit as u8);
@@ -232,6 +338,43 @@ impl PoolState {
         ))
     }
 
+    /// Returns the token prices recorded by the observation.
+    /// Prices are converted to ui amount space with the ui amount multipliers of the mints when `ui_amount_pricing` is enabled.
+    pub fn observation_price_x32(
+        &self,
+        token_0_price_x32: u128,
+        token_1_price_x32: u128,
+        token_0_ui_multiplier_x32: u64,
+        token_1_ui_multiplier_x32: u64,
+    ) -> (u128, u128) {
+        if !self.ui_amount_pricing {
+            return (token_0_price_x32, token_1_price_x32);
+        }
+        let scale = |price_x32: u128, numerator: u64, denominator: u64| {
+            let denominator = if denominator == 0 {
+                Q32
+            } else {
+                u128::from(denominator)
+            };
+            price_x32
+                .checked_mul(u128::from(numerator))
+                .map(|value| value / denominator)
+                .unwrap_or_else(|| price_x32 / denominator * u128::from(numerator))
+        };
+        (
+            scale(
+                token_0_price_x32,
+                token_1_ui_multiplier_x32,
+                token_0_ui_multiplier_x32,
+            ),
+            scale(
+                token_1_price_x32,
+                token_0_ui_multiplier_x32,
+                token_1_ui_multiplier_x32,
+            ),
+        )
+    }
+
     pub fn update_lp_supply(
         &mut self,
         liquidity_delta: u64,
@@ -371,6 +514,57 @@ impl PoolState {
         };
         Ok(())
     }
+
+    /// Checks the post swap token_0 price against the observation twap when the circuit breaker is enabled.
+    /// `price_x32` holds the pre swap prices of token_0 and token_1 in the price space of the observations,
+    /// converted with the current ui amount multipliers of the mints, which convert the post swap price too.
+    /// A swap beyond the max deviation is rejected, or swaps of the pool are paused after it when `circuit_breaker_pause` is set.
+    /// Returns the post swap and twap token_0 prices when the circuit breaker paused the pool
+    pub fn check_circuit_breaker(
+        &mut self,
+        observation_state: &ObservationState,
+        block_timestamp: u64,
+        (token_0_price_x32, token_1_price_x32): (u128, u128),
+        (token_0_ui_multiplier_x32, token_1_ui_multiplier_x32): (u64, u64),
+        trade_direction: TradeDirection,
+        (new_input_vault_amount, new_output_vault_amount): (u128, u128),
+    ) -> Result<Option<(u128, u128)>> {
+        if self.circuit_breaker_bps == 0 {
+            return Ok(None);
+        }
+        // skip until the observations cover the window
+        let twap_token_0_price_x32 = match observation_state.twap_price_x32(
+            self.circuit_breaker_window,
+            block_timestamp,
+            token_0_price_x32,
+            token_1_price_x32,
+        ) {
+            Some((twap_token_0_price_x32, _)) => twap_token_0_price_x32,
+            None => return Ok(None),
+        };
+        let (vault_0, vault_1) = match trade_direction {
+            TradeDirection::ZeroForOne => (new_input_vault_amount, new_output_vault_amount),
+            TradeDirection::OneForZero => (new_output_vault_amount, new_input_vault_amount),
+        };
+        let (token_0_price_x32, _) = self.observation_price_x32(
+            vault_1 * Q32 / vault_0,
+            0,
+            token_0_ui_multiplier_x32,
+            token_1_ui_multiplier_x32,
+        );
+        if !self.exceeds_circuit_breaker(token_0_price_x32, twap_token_0_price_x32) {
+            return Ok(None);
+        }
+        require!(self.circuit_breaker_pause, ErrorCode::CircuitBreakerTripped);
+        self.set_status_by_bit(PoolStatusBitIndex::Swap, PoolStatusBitFlag::Disable);
+        self.circuit_breaker_tripped = true;
+        Ok(Some((token_0_price_x32, twap_token_0_price_x32)))
+    }
+
+    fn exceeds_circuit_breaker(&self, price_x32: u128, twap_price_x32: u128) -> bool {
+        price_x32.abs_diff(twap_price_x32) * u128::from(CIRCUIT_BREAKER_BPS_DENOMINATOR)
+            > twap_price_x32 * u128::from(self.circuit_breaker_bps)
+    }
 }
 
 #[cfg(test)]
@@ -382,6 +576,188 @@ pub mod pool_test {
         assert_eq!(std::mem::size_of::<PoolState>(), PoolState::LEN - 8)
     }
 
+    #[test]
+    fn lp_mint_metadata_test() {
+        let metadata = LpMintMetadata {
+            token_0_symbol: "SOL".to_string(),
+            token_1_symbol: "USDC".to_string(),
+            uri: "".to_string(),
+        };
+        let (name, symbol, _) = metadata.to_token_metadata().unwrap();
+        assert_eq!(name, "KDLK-LP SOL/USDC");
+        assert_eq!(symbol, LP_MINT_SYMBOL);
+
+        let metadata = LpMintMetadata {
+            token_0_symbol: "".to_string(),
+            ..metadata
+        };
+        assert!(metadata.to_token_metadata().is_err());
+    }
+
+    #[test]
+    fn observation_price_x32_test() {
+        let mut pool_state = PoolState::default();
+        let price_x32 = 2 * Q32;
+        let multiplier_x32 = (Q32 + Q32 / 2) as u64;
+        assert_eq!(
+            pool_state.observation_price_x32(price_x32, price_x32, multiplier_x32, Q32 as u64),
+            (price_x32, price_x32)
+        );
+
+        pool_state.ui_amount_pricing = true;
+        // token_0 ui amount is 1.5 times the raw amount
+        assert_eq!(
+            pool_state.observation_price_x32(price_x32, price_x32, multiplier_x32, Q32 as u64),
+            (price_x32 * 2 / 3, price_x32 * 3 / 2)
+        );
+    }
+
+    #[test]
+    fn set_creator_fee_test() {
+        let mut pool_state = PoolState::default();
+        pool_state.set_creator_fee(true, CreatorFeeOn::BothToken);
+        pool_state
+            .update_fees(0, 0, 100, TradeDirection::ZeroForOne)
+            .unwrap();
+        assert_eq!(
+            { pool_state.creator_fees_token_0 } + { pool_state.creator_fees_token_1 },
+            100
+        );
+
+        pool_state.set_creator_fee(false, CreatorFeeOn::OnlyToken1);
+        assert!(!pool_state.enable_creator_fee);
+        assert_eq!(pool_state.creator_fee_on, CreatorFeeOn::OnlyToken1.to_u8());
+        assert_eq!(
+            { pool_state.creator_fees_token_0 } + { pool_state.creator_fees_token_1 },
+            100
+        );
+    }
+
+    #[test]
+    fn circuit_breaker_test() {
+        let mut observation_state = ObservationState::default();
+        let start = 1_000_000;
+        observation_state.update(start, 0, 0);
+        observation_state.update(start + 60, 2 * Q32, Q32 / 2);
+        let now = start + 60;
+        let price_x32 = (2 * Q32, Q32 / 2);
+        let multiplier_x32 = (Q32 as u64, Q32 as u64);
+
+        let mut pool_state = PoolState::default();
+        // disabled
+        assert_eq!(
+            pool_state
+                .check_circuit_breaker(
+                    &observation_state,
+                    now,
+                    price_x32,
+                    multiplier_x32,
+                    TradeDirection::ZeroForOne,
+                    (100, 400),
+                )
+                .unwrap(),
+            None
+        );
+
+        pool_state.circuit_breaker_bps = 1000;
+        pool_state.circuit_breaker_window = 60;
+        // within 10% of the twap price 2
+        assert_eq!(
+            pool_state
+                .check_circuit_breaker(
+                    &observation_state,
+                    now,
+                    price_x32,
+                    multiplier_x32,
+                    TradeDirection::ZeroForOne,
+                    (100, 210),
+                )
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            pool_state
+                .check_circuit_breaker(
+                    &observation_state,
+                    now,
+                    price_x32,
+                    multiplier_x32,
+                    TradeDirection::OneForZero,
+                    (190, 100),
+                )
+                .unwrap(),
+            None
+        );
+        // rejected beyond the deviation
+        assert!(pool_state
+            .check_circuit_breaker(
+                &observation_state,
+                now,
+                price_x32,
+                multiplier_x32,
+                TradeDirection::ZeroForOne,
+                (100, 230),
+            )
+            .is_err());
+
+        pool_state.circuit_breaker_pause = true;
+        assert_eq!(
+            pool_state
+                .check_circuit_breaker(
+                    &observation_state,
+                    now,
+                    price_x32,
+                    multiplier_x32,
+                    TradeDirection::OneForZero,
+                    (300, 100),
+                )
+                .unwrap(),
+            Some((3 * Q32, 2 * Q32))
+        );
+        assert!(pool_state.circuit_breaker_tripped);
+        assert!(!pool_state.get_status_by_bit(PoolStatusBitIndex::Swap));
+
+        // not covered by the observations
+        pool_state.circuit_breaker_window = 120;
+        assert_eq!(
+            pool_state
+                .check_circuit_breaker(
+                    &observation_state,
+                    now,
+                    price_x32,
+                    multiplier_x32,
+                    TradeDirection::ZeroForOne,
+                    (100, 400),
+                )
+                .unwrap(),
+            None
+        );
+
+        // the multipliers of a fresh observation are not recorded yet,
+        // the post swap price is converted with the current multipliers
+        pool_state.ui_amount_pricing = true;
+        pool_state.circuit_breaker_window = 60;
+        assert_eq!(
+            ({ observation_state.token_0_ui_multiplier_x32 }, {
+                observation_state.token_1_ui_multiplier_x32
+            }),
+            (0, 0)
+        );
+        assert_eq!(
+            pool_state
+                .check_circuit_breaker(
+                    &observation_state,
+                    now,
+                    price_x32,
+                    multiplier_x32,
+                    TradeDirection::ZeroForOne,
+                    (100, 210),
+                )
+                .unwrap(),
+            None
+        );
+    }
+
     mod pool_status_test {
         use super::*;
 
@@ -0,0 +1,47 @@
+use crate::states::PoolStatusBitIndex;
+use anchor_lang::prelude::*;
+use std::ops::BitAnd;
+
+pub const PROTOCOL_STATE_SEED: &str = "protocol_state";
+/// Upper bound of the timelock delay, in seconds
+pub const MAX_TIMELOCK_DELAY: u64 = 7 * 24 * 60 * 60;
+
+/// Global state of the protocol, holds the admin
+#[account]
+#[derive(Default, Debug)]
+pub struct ProtocolState {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The admin of the protocol
+    pub admin: Pubkey,
+    /// The proposed admin, becomes the admin once accepted
+    pub pending_admin: Pubkey,
+    /// Seconds a queued config update must wait before it can be executed, 0 disables the timelock
+    pub timelock_delay: u64,
+    /// The guardian can only disable pools, set by the admin
+    pub guardian: Pubkey,
+    /// Bitwise representation of the state of all pools, checked alongside the pool status
+    /// bit0, 1: disable deposit(value is 1), 0: normal
+    /// bit1, 1: disable withdraw(value is 2), 0: normal
+    /// bit2, 1: disable swap(value is 4), 0: normal
+    pub status: u8,
+    pub padding1: [u8; 7],
+    /// The pool protocol fees are swapped through to buy back the K token
+    pub buyback_pool: Pubkey,
+    /// The K token mint of the buyback pool
+    pub buyback_mint: Pubkey,
+    /// The token account receiving the bought back K token, default pubkey burns it
+    pub buyback_destination: Pubkey,
+    /// padding
+    pub padding: [u64; 14],
+}
+
+impl ProtocolState {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 32 + 1 + 7 + 32 * 3 + 8 * 14;
+
+    /// Get status by bit, if it is `noraml` status, return true
+    pub fn get_status_by_bit(&self, bit: PoolStatusBitIndex) -> bool {
+        let status = 1u8 << (bit as u8);
+        self.status.bitand(status) == 0
+    }
+}
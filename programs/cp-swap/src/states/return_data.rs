@@ -0,0 +1,165 @@
+use crate::curve::calculator::SwapResult;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::set_return_data;
+
+/// The result of a swap, set as the return data of the swap instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SwapReturnData {
+    /// The input amount, including trade fees, excluding transfer fees
+    pub input_amount: u64,
+    /// The output amount, including transfer fees
+    pub output_amount: u64,
+    /// The transfer fee paid on top of the input amount
+    pub input_transfer_fee: u64,
+    /// The transfer fee deducted from the output amount
+    pub output_transfer_fee: u64,
+    /// Amount of trade fees
+    pub trade_fee: u64,
+    /// Amount of trade fees going to protocol, excluding the referral fee
+    pub protocol_fee: u64,
+    /// Amount of trade fees going to fund
+    pub fund_fee: u64,
+    /// Amount of creator fees
+    pub creator_fee: u64,
+    /// Amount of protocol fees paid to the referrer
+    pub referral_fee: u64,
+    /// The input vault reserve after the swap, excluding fees
+    pub new_input_vault_amount: u64,
+    /// The output vault reserve after the swap, excluding fees
+    pub new_output_vault_amount: u64,
+}
+
+/// The result of a deposit or withdraw, set as the return data of those instructions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LpChangeReturnData {
+    /// The lp token amount minted or burned
+    pub lp_token_amount: u64,
+    /// The token_0 amount moved into or out of the vault, excluding transfer fees
+    pub token_0_amount: u64,
+    /// The token_1 amount moved into or out of the vault, excluding transfer fees
+    pub token_1_amount: u64,
+    /// The token_0 transfer fee
+    pub token_0_transfer_fee: u64,
+    /// The token_1 transfer fee
+    pub token_1_transfer_fee: u64,
+    /// The token_0 vault reserve after the change, excluding fees
+    pub new_token_0_vault_amount: u64,
+    /// The token_1 vault reserve after the change, excluding fees
+    pub new_token_1_vault_amount: u64,
+}
+
+impl SwapReturnData {
+    /// The return data of a swap, shared by the swap and quote instructions.
+    /// The referral fee is paid out of the protocol fee
+    pub fn new(
+        result: &SwapResult,
+        input_transfer_fee: u64,
+        output_transfer_fee: u64,
+        referral_fee: u64,
+    ) -> Self {
+        SwapReturnData {
+            input_amount: u64::try_from(result.input_amount).unwrap(),
+            output_amount: u64::try_from(result.output_amount).unwrap(),
+            input_transfer_fee,
+            output_transfer_fee,
+            trade_fee: u64::try_from(result.trade_fee).unwrap(),
+            protocol_fee: u64::try_from(result.protocol_fee).unwrap() - referral_fee,
+            fund_fee: u64::try_from(result.fund_fee).unwrap(),
+            creator_fee: u64::try_from(result.creator_fee).unwrap(),
+            referral_fee,
+            new_input_vault_amount: u64::try_from(result.new_input_vault_amount).unwrap(),
+            new_output_vault_amount: u64::try_from(result.new_output_vault_amount).unwrap(),
+        }
+    }
+
+    pub fn set(&self) -> Result<()> {
+        set_return_data(&self.try_to_vec()?);
+        Ok(())
+    }
+
+    /// Decode the return data left by a swap instruction of this program
+    #[cfg(feature = "cpi")]
+    pub fn get() -> Result<Self> {
+        get_return_data()
+    }
+}
+
+impl LpChangeReturnData {
+    pub fn set(&self) -> Result<()> {
+        set_return_data(&self.try_to_vec()?);
+        Ok(())
+    }
+
+    /// Decode the return data left by a deposit or withdraw instruction of this program
+    #[cfg(feature = "cpi")]
+    pub fn get() -> Result<Self> {
+        get_return_data()
+    }
+}
+
+#[cfg(feature = "cpi")]
+fn get_return_data<T: AnchorDeserialize>() -> Result<T> {
+    let (program_id, data) = anchor_lang::solana_program::program::get_return_data()
+        .ok_or(crate::error::ErrorCode::InvalidReturnData)?;
+    require_keys_eq!(
+        program_id,
+        crate::id(),
+        crate::error::ErrorCode::InvalidReturnData
+    );
+    T::try_from_slice(&data).map_err(|_| crate::error::ErrorCode::InvalidReturnData.into())
+}
+
+#[cfg(test)]
+mod return_data_test {
+    use super::*;
+
+    #[test]
+    fn swap_return_data_roundtrip_test() {
+        let data = SwapReturnData {
+            input_amount: 1000,
+            output_amount: 990,
+            input_transfer_fee: 1,
+            output_transfer_fee: 2,
+            trade_fee: 3,
+            protocol_fee: 4,
+            fund_fee: 5,
+            creator_fee: 6,
+            referral_fee: 7,
+            new_input_vault_amount: 8,
+            new_output_vault_amount: 9,
+        };
+        let bytes = data.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), 11 * 8);
+        assert_eq!(SwapReturnData::try_from_slice(&bytes).unwrap(), data);
+    }
+
+    #[test]
+    fn swap_return_data_new_test() {
+        let result = SwapResult {
+            new_input_vault_amount: 1_001_000,
+            new_output_vault_amount: 999_010,
+            input_amount: 1000,
+            output_amount: 990,
+            trade_fee: 10,
+            protocol_fee: 4,
+            fund_fee: 2,
+            creator_fee: 1,
+        };
+        assert_eq!(
+            SwapReturnData::new(&result, 5, 6, 1),
+            SwapReturnData {
+                input_amount: 1000,
+                output_amount: 990,
+                input_transfer_fee: 5,
+                output_transfer_fee: 6,
+                trade_fee: 10,
+                protocol_fee: 3,
+                fund_fee: 2,
+                creator_fee: 1,
+                referral_fee: 1,
+                new_input_vault_amount: 1_001_000,
+                new_output_vault_amount: 999_010,
+            }
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+pub const USER_STAKE_SEED: &str = "user_stake";
+pub const STAKE_VAULT_SEED: &str = "stake_vault";
+/// Seconds staked K tokens stay locked after the latest stake
+pub const STAKE_LOCK_DURATION: u64 = 24 * 60 * 60;
+
+/// The K tokens a user locked to reach the staking tiers of the configs
+#[account]
+#[derive(Default, Debug)]
+pub struct UserStake {
+    /// Bump to identify PDA
+    pub bump: u8,
+    /// The owner of the staked tokens
+    pub owner: Pubkey,
+    /// The staked K token mint
+    pub mint: Pubkey,
+    /// The staked amount
+    pub amount: u64,
+    /// The timestamp after which the staked tokens can be withdrawn
+    pub unlock_time: u64,
+    /// padding
+    pub padding: [u64; 8],
+}
+
+impl UserStake {
+    pub const LEN: usize = 8 + 1 + 32 + 32 + 8 + 8 + 8 * 8;
+}
@@ -0,0 +1,24 @@
+use crate::states::AmmConfigUpdate;
+use anchor_lang::prelude::*;
+
+pub const QUEUED_CONFIG_UPDATE_SEED: &str = "queued_config_update";
+
+/// A config update waiting for the timelock delay, one per amm config
+#[account]
+#[derive(Default, Debug)]
+pub struct QueuedConfigUpdate {
+    /// The amm config to be updated
+    pub amm_config: Pubkey,
+    /// The fields to be updated
+    pub params: AmmConfigUpdate,
+    /// The timestamp the update was queued
+    pub queued_at: u64,
+    /// The timestamp after which the update can be executed
+    pub executable_at: u64,
+    /// padding
+    pub padding: [u64; 8],
+}
+
+impl QueuedConfigUpdate {
+    pub const LEN: usize = 8 + 32 + AmmConfigUpdate::LEN + 8 + 8 + 8 * 8;
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+pub const TRANSFER_HOOK_APPROVAL_SEED: &str = "transfer_hook_approval";
+
+/// Marks a transfer hook program as approved by the admin.
+/// Mints with a transfer hook can only be used in pools if their hook program is approved.
+#[account]
+#[derive(Default, Debug)]
+pub struct TransferHookApproval {
+    /// The approved transfer hook program
+    pub program_id: Pubkey,
+    /// padding
+    pub padding: [u64; 8],
+}
+
+impl TransferHookApproval {
+    pub const LEN: usize = 8 + 32 + 8 * 8;
+}
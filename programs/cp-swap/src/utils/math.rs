@@ -39,3 +39,75 @@ impl DownCast for u128 {
         }
     }
 }
+
+/// ln(2) in Q64.64
+const LN_2_X64: u128 = 12786308645202655659;
+
+/// Returns e^x in Q32.32 for `x_x64` in Q64.64, None when the result exceeds u64.
+/// The fractional part of x / ln(2) is evaluated by its Taylor series, the integral part by shifting.
+pub fn exp_x32(x_x64: i128) -> Option<u64> {
+    let ln_2_x64 = LN_2_X64 as i128;
+    let k = x_x64.div_euclid(ln_2_x64);
+    let r_x64 = U256::from(x_x64.rem_euclid(ln_2_x64) as u128);
+    // e^r for r in [0, ln(2))
+    let mut exp_r_x64 = U256::one() << 64;
+    let mut term_x64 = exp_r_x64;
+    for i in 1..32u64 {
+        term_x64 = ((term_x64 * r_x64) >> 64) / i;
+        if term_x64.is_zero() {
+            break;
+        }
+        exp_r_x64 += term_x64;
+    }
+    // e^x = e^r * 2^k, e^r is in [1, 2)
+    let exp_x32 = if k >= 0 {
+        if k >= 32 {
+            return None;
+        }
+        (exp_r_x64 << (k as usize)) >> 32
+    } else if k > -96 {
+        exp_r_x64 >> ((32 - k) as usize)
+    } else {
+        U256::zero()
+    };
+    if exp_x32 > U256::from(u64::MAX) {
+        return None;
+    }
+    Some(exp_x32.as_u64())
+}
+
+#[cfg(test)]
+pub mod math_test {
+    use super::*;
+
+    #[test]
+    fn exp_x32_test() {
+        let q64 = (1u128 << 64) as f64;
+        for x in [
+            -20.0,
+            -1.5,
+            -0.01,
+            0.0,
+            1e-9,
+            0.05,
+            std::f64::consts::LN_2,
+            1.0,
+            3.3,
+            21.9,
+        ] {
+            let expected = x.exp() * (1u64 << 32) as f64;
+            let actual = exp_x32((x * q64) as i128).unwrap() as f64;
+            assert!(
+                (actual - expected).abs() <= expected * 1e-9 + 1.0,
+                "x: {}, actual: {}, expected: {}",
+                x,
+                actual,
+                expected
+            );
+        }
+        assert_eq!(exp_x32(0), Some(1 << 32));
+        // e^22.2 > 2^32
+        assert_eq!(exp_x32((22.2 * q64) as i128), None);
+        assert_eq!(exp_x32(-100 << 64), Some(0));
+    }
+}
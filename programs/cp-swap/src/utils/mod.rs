@@ -2,8 +2,10 @@ pub mod account_load;
 pub mod math;
 pub mod token;
 pub mod pyth;
+pub mod swap;
 
 pub use account_load::*;
 pub use math::*;
 pub use token::*;
 pub use pyth::*;
+pub use swap::*;
@@ -0,0 +1,380 @@
+use crate::curve::calculator::CurveCalculator;
+use crate::curve::fees::FEE_RATE_DENOMINATOR_VALUE;
+use crate::curve::TradeDirection;
+use crate::error::ErrorCode;
+use crate::states::*;
+use crate::utils::token::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+/// The denominator of the max deviations of execution prices below the twap, in basis points
+pub const TWAP_DEVIATION_BPS_DENOMINATOR: u64 = 10000;
+
+/// The accounts of a pool swapped through by `swap_base_input_in_pool`
+pub struct PoolSwapAccounts<'a, 'info> {
+    pub pool_id: Pubkey,
+    pub amm_config: &'a AmmConfig,
+    pub protocol_state: &'a ProtocolState,
+    pub input_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub output_vault: &'a InterfaceAccount<'info, TokenAccount>,
+    pub input_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub output_token_mint: &'a InterfaceAccount<'info, Mint>,
+    pub observation_state: &'a AccountLoader<'info, ObservationState>,
+}
+
+/// The outcome of `swap_base_input_in_pool`
+pub struct PoolSwapResult {
+    pub trade_direction: TradeDirection,
+    /// The pool price of token_0 before the swap, Q32.32
+    pub token_0_price_x32: u128,
+    /// The pool price of token_1 before the swap, Q32.32
+    pub token_1_price_x32: u128,
+    /// The ui amount multipliers of token_0 and token_1 at the swap, Q32.32
+    pub token_0_ui_multiplier_x32: u64,
+    pub token_1_ui_multiplier_x32: u64,
+    /// The amount transferred into the input vault, including the transfer fee
+    pub amount_in: u64,
+    pub input_transfer_fee: u64,
+    /// The amount transferred out of the output vault, including the transfer fee
+    pub amount_out: u64,
+    pub output_transfer_fee: u64,
+    pub trade_fee: u64,
+    pub protocol_fee: u64,
+    pub fund_fee: u64,
+    pub creator_fee: u64,
+}
+
+impl PoolSwapResult {
+    /// The amount received from the output vault, excluding the transfer fee
+    pub fn amount_received(&self) -> u64 {
+        self.amount_out - self.output_transfer_fee
+    }
+
+    /// Checks the execution price of the input token is not below its twap over the `window` seconds
+    /// before `block_timestamp` by more than `max_twap_deviation_bps`. The execution price includes
+    /// the trade fee, see `check_max_twap_deviation_bps`
+    pub fn check_twap_deviation(
+        &self,
+        pool_state: &PoolState,
+        observation_state: &ObservationState,
+        block_timestamp: u64,
+        window: u64,
+        max_twap_deviation_bps: u64,
+    ) -> Result<()> {
+        let (token_0_price_x32, token_1_price_x32) = pool_state.observation_price_x32(
+            self.token_0_price_x32,
+            self.token_1_price_x32,
+            self.token_0_ui_multiplier_x32,
+            self.token_1_ui_multiplier_x32,
+        );
+        let (twap_token_0_price_x32, twap_token_1_price_x32) = observation_state
+            .twap_price_x32(
+                window,
+                block_timestamp,
+                token_0_price_x32,
+                token_1_price_x32,
+            )
+            .ok_or(ErrorCode::TwapUnavailable)?;
+        let execution_price_x32 = (u128::from(self.amount_out) << 32)
+            / u128::from(self.amount_in - self.input_transfer_fee);
+        let (execution_token_0_price_x32, execution_token_1_price_x32) = pool_state
+            .observation_price_x32(
+                execution_price_x32,
+                execution_price_x32,
+                self.token_0_ui_multiplier_x32,
+                self.token_1_ui_multiplier_x32,
+            );
+        let (execution_price_x32, twap_price_x32) = match self.trade_direction {
+            TradeDirection::ZeroForOne => (execution_token_0_price_x32, twap_token_0_price_x32),
+            TradeDirection::OneForZero => (execution_token_1_price_x32, twap_token_1_price_x32),
+        };
+        require_gte!(
+            execution_price_x32 * u128::from(TWAP_DEVIATION_BPS_DENOMINATOR),
+            twap_price_x32 * u128::from(TWAP_DEVIATION_BPS_DENOMINATOR - max_twap_deviation_bps),
+            ErrorCode::ExceededSlippage
+        );
+        Ok(())
+    }
+}
+
+/// Checks the pool and the protocol allow swaps and the pool is open at `block_timestamp`
+pub fn check_swap_status(
+    pool_state: &PoolState,
+    protocol_state: &ProtocolState,
+    block_timestamp: u64,
+) -> Result<()> {
+    if !pool_state.get_status_by_bit(PoolStatusBitIndex::Swap)
+        || !protocol_state.get_status_by_bit(PoolStatusBitIndex::Swap)
+        || block_timestamp < pool_state.open_time
+    {
+        return err!(ErrorCode::NotApproved);
+    }
+    Ok(())
+}
+
+/// The ui amount multipliers of the mints of a swap and the pre swap prices of the pool
+pub struct SwapPrices {
+    pub input_ui_multiplier_x32: u64,
+    pub output_ui_multiplier_x32: u64,
+    pub token_0_ui_multiplier_x32: u64,
+    pub token_1_ui_multiplier_x32: u64,
+    /// The pre swap prices of token_0 and token_1 in the price space of the observations
+    pub pre_swap_price_x32: (u128, u128),
+}
+
+impl SwapPrices {
+    /// Reads the ui amount multipliers of the mints at `block_timestamp` and converts the pre swap
+    /// pool prices `(token_0_price_x32, token_1_price_x32)` with them
+    pub fn new(
+        pool_state: &PoolState,
+        trade_direction: TradeDirection,
+        (token_0_price_x32, token_1_price_x32): (u128, u128),
+        input_token_mint: &AccountInfo,
+        output_token_mint: &AccountInfo,
+        block_timestamp: u64,
+    ) -> Result<Self> {
+        let input_ui_multiplier_x32 =
+            get_ui_amount_multiplier_x32(input_token_mint, block_timestamp as i64)?;
+        let output_ui_multiplier_x32 =
+            get_ui_amount_multiplier_x32(output_token_mint, block_timestamp as i64)?;
+        let (token_0_ui_multiplier_x32, token_1_ui_multiplier_x32) = match trade_direction {
+            TradeDirection::ZeroForOne => (input_ui_multiplier_x32, output_ui_multiplier_x32),
+            TradeDirection::OneForZero => (output_ui_multiplier_x32, input_ui_multiplier_x32),
+        };
+        Ok(Self {
+            input_ui_multiplier_x32,
+            output_ui_multiplier_x32,
+            token_0_ui_multiplier_x32,
+            token_1_ui_multiplier_x32,
+            pre_swap_price_x32: pool_state.observation_price_x32(
+                token_0_price_x32,
+                token_1_price_x32,
+                token_0_ui_multiplier_x32,
+                token_1_ui_multiplier_x32,
+            ),
+        })
+    }
+
+    /// Checks the circuit breaker of the pool against the post swap vault amounts,
+    /// emits `CircuitBreakerEvent` when it pauses the pool
+    pub fn check_circuit_breaker(
+        &self,
+        pool_state: &mut PoolState,
+        pool_id: Pubkey,
+        observation_state: &ObservationState,
+        block_timestamp: u64,
+        trade_direction: TradeDirection,
+        new_vault_amounts: (u128, u128),
+    ) -> Result<()> {
+        if let Some((token_0_price_x32, twap_token_0_price_x32)) = pool_state
+            .check_circuit_breaker(
+                observation_state,
+                block_timestamp,
+                self.pre_swap_price_x32,
+                (
+                    self.token_0_ui_multiplier_x32,
+                    self.token_1_ui_multiplier_x32,
+                ),
+                trade_direction,
+                new_vault_amounts,
+            )?
+        {
+            emit!(CircuitBreakerEvent {
+                pool_id,
+                token_0_price_x32,
+                twap_token_0_price_x32,
+                change_type: 0,
+            });
+        }
+        Ok(())
+    }
+
+    /// Records the pre swap prices and the ui amount multipliers to the observation
+    /// and the recent epoch to the pool
+    pub fn update_observation(
+        &self,
+        pool_state: &mut PoolState,
+        observation_state: &mut ObservationState,
+        block_timestamp: u64,
+    ) -> Result<()> {
+        let (token_0_price_x32, token_1_price_x32) = self.pre_swap_price_x32;
+        observation_state.update(block_timestamp, token_0_price_x32, token_1_price_x32);
+        observation_state.update_ui_amount_multiplier(
+            self.token_0_ui_multiplier_x32,
+            self.token_1_ui_multiplier_x32,
+        );
+        pool_state.recent_epoch = Clock::get()?.epoch;
+        Ok(())
+    }
+}
+
+/// Checks a max deviation of the execution price below the twap, in basis points, can be met.
+/// The execution price includes the trade fee, so the deviation has to exceed the trade fee rate
+pub fn check_max_twap_deviation_bps(
+    max_twap_deviation_bps: u64,
+    trade_fee_rate: u64,
+) -> Result<()> {
+    require_gte!(
+        TWAP_DEVIATION_BPS_DENOMINATOR,
+        max_twap_deviation_bps,
+        ErrorCode::InvalidInput
+    );
+    require_gt!(
+        u128::from(max_twap_deviation_bps) * u128::from(FEE_RATE_DENOMINATOR_VALUE),
+        u128::from(trade_fee_rate) * u128::from(TWAP_DEVIATION_BPS_DENOMINATOR),
+        ErrorCode::InvalidInput
+    );
+    Ok(())
+}
+
+/// Swap `amount_in` of the input token through the pool at the trade fee rate of the config,
+/// for the swaps the program makes on behalf of orders, vaults and the protocol.
+/// Checks the pool can swap and the circuit breaker, accrues the fees, records the price to the
+/// observation and emits `SwapEvent`. The caller moves `amount_in` into the input vault and
+/// `amount_out` out of the output vault.
+pub fn swap_base_input_in_pool(
+    pool_state: &mut PoolState,
+    accounts: &PoolSwapAccounts,
+    amount_in: u64,
+) -> Result<PoolSwapResult> {
+    let block_timestamp = Clock::get()?.unix_timestamp as u64;
+    check_swap_status(pool_state, accounts.protocol_state, block_timestamp)?;
+
+    let input_transfer_fee =
+        get_transfer_fee(&accounts.input_token_mint.to_account_info(), amount_in)?;
+    let actual_amount_in = amount_in.saturating_sub(input_transfer_fee);
+    require_gt!(actual_amount_in, 0);
+
+    let SwapParams {
+        trade_direction,
+        total_input_token_amount,
+        total_output_token_amount,
+        token_0_price_x64,
+        token_1_price_x64,
+        is_creator_fee_on_input,
+    } = pool_state.get_swap_params(
+        accounts.input_vault.key(),
+        accounts.output_vault.key(),
+        accounts.input_vault.amount,
+        accounts.output_vault.amount,
+    )?;
+    let constant_before = u128::from(total_input_token_amount)
+        .checked_mul(u128::from(total_output_token_amount))
+        .unwrap();
+
+    let creator_fee_rate = pool_state.adjust_creator_fee_rate(accounts.amm_config.creator_fee_rate);
+    let result = CurveCalculator::swap_base_input(
+        u128::from(actual_amount_in),
+        u128::from(total_input_token_amount),
+        u128::from(total_output_token_amount),
+        accounts.amm_config.trade_fee_rate,
+        creator_fee_rate,
+        accounts.amm_config.protocol_fee_rate,
+        accounts.amm_config.fund_fee_rate,
+        is_creator_fee_on_input,
+    )
+    .ok_or(ErrorCode::ZeroTradingTokens)?;
+    require_eq!(
+        u64::try_from(result.input_amount).unwrap(),
+        actual_amount_in
+    );
+
+    let constant_after = result
+        .new_input_vault_amount
+        .checked_mul(result.new_output_vault_amount)
+        .unwrap();
+    require_gte!(constant_after, constant_before);
+
+    let amount_out = u64::try_from(result.output_amount).unwrap();
+    let output_transfer_fee =
+        get_transfer_fee(&accounts.output_token_mint.to_account_info(), amount_out)?;
+    require_gt!(amount_out, output_transfer_fee);
+
+    let swap_prices = SwapPrices::new(
+        pool_state,
+        trade_direction,
+        (token_0_price_x64, token_1_price_x64),
+        &accounts.input_token_mint.to_account_info(),
+        &accounts.output_token_mint.to_account_info(),
+        block_timestamp,
+    )?;
+    swap_prices.check_circuit_breaker(
+        pool_state,
+        accounts.pool_id,
+        &*accounts.observation_state.load()?,
+        block_timestamp,
+        trade_direction,
+        (
+            result.new_input_vault_amount,
+            result.new_output_vault_amount,
+        ),
+    )?;
+
+    let swap_result = PoolSwapResult {
+        trade_direction,
+        token_0_price_x32: token_0_price_x64,
+        token_1_price_x32: token_1_price_x64,
+        token_0_ui_multiplier_x32: swap_prices.token_0_ui_multiplier_x32,
+        token_1_ui_multiplier_x32: swap_prices.token_1_ui_multiplier_x32,
+        amount_in,
+        input_transfer_fee,
+        amount_out,
+        output_transfer_fee,
+        trade_fee: u64::try_from(result.trade_fee).unwrap(),
+        protocol_fee: u64::try_from(result.protocol_fee).unwrap(),
+        fund_fee: u64::try_from(result.fund_fee).unwrap(),
+        creator_fee: u64::try_from(result.creator_fee).unwrap(),
+    };
+    pool_state.update_fees(
+        swap_result.protocol_fee,
+        swap_result.fund_fee,
+        swap_result.creator_fee,
+        trade_direction,
+    )?;
+
+    swap_prices.update_observation(
+        pool_state,
+        &mut *accounts.observation_state.load_mut()?,
+        block_timestamp,
+    )?;
+
+    emit!(SwapEvent {
+        pool_id: accounts.pool_id,
+        input_vault_before: total_input_token_amount,
+        output_vault_before: total_output_token_amount,
+        input_amount: actual_amount_in,
+        output_amount: amount_out,
+        input_transfer_fee,
+        output_transfer_fee,
+        base_input: true,
+        input_mint: accounts.input_token_mint.key(),
+        output_mint: accounts.output_token_mint.key(),
+        trade_fee: swap_result.trade_fee,
+        creator_fee: swap_result.creator_fee,
+        creator_fee_on_input: is_creator_fee_on_input,
+        input_ui_amount: to_ui_amount(actual_amount_in, swap_prices.input_ui_multiplier_x32),
+        output_ui_amount: to_ui_amount(amount_out, swap_prices.output_ui_multiplier_x32),
+        referral_fee: 0,
+    });
+    Ok(swap_result)
+}
+
+#[cfg(test)]
+mod swap_test {
+    use super::*;
+
+    #[test]
+    fn check_max_twap_deviation_bps_test() {
+        // 0.25% trade fee
+        let trade_fee_rate = 2500;
+        assert!(check_max_twap_deviation_bps(25, trade_fee_rate).is_err());
+        assert!(check_max_twap_deviation_bps(26, trade_fee_rate).is_ok());
+        assert!(
+            check_max_twap_deviation_bps(TWAP_DEVIATION_BPS_DENOMINATOR, trade_fee_rate).is_ok()
+        );
+        assert!(
+            check_max_twap_deviation_bps(TWAP_DEVIATION_BPS_DENOMINATOR + 1, trade_fee_rate)
+                .is_err()
+        );
+    }
+}
@@ -1,26 +1,42 @@
 use crate::error::ErrorCode;
-use anchor_lang::{prelude::*, system_program};
+use crate::states::{
+    AmmConfig, MintAllowlistEntry, MintPolicyBitIndex, TransferHookApproval, MINT_ALLOWLIST_SEED,
+    Q32, TRANSFER_HOOK_APPROVAL_SEED,
+};
+use crate::utils::math::exp_x32;
+use anchor_lang::{prelude::*, solana_program::program_pack::Pack, system_program};
 use anchor_spl::{
     token::{Token, TokenAccount},
     token_2022::{self},
-    token_interface::{initialize_account3, InitializeAccount3, Mint},
+    token_interface::{
+        initialize_account3, initialize_mint2, metadata_pointer_initialize,
+        spl_token_metadata_interface::state::TokenMetadata, token_metadata_initialize,
+        InitializeAccount3, InitializeMint2, MetadataPointerInitialize, Mint,
+        TokenMetadataInitialize,
+    },
 };
+use anchor_spl::token_interface::spl_pod::optional_keys::OptionalNonZeroPubkey;
 use anchor_spl::token_interface::spl_token_2022::{
     self,
     extension::{
+        interest_bearing_mint::InterestBearingConfig,
+        permanent_delegate::PermanentDelegate,
         transfer_fee::{TransferFeeConfig, MAX_FEE_BASIS_POINTS},
-        BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+        transfer_hook, BaseStateWithExtensions, ExtensionType, StateWithExtensions,
     },
+    onchain,
 };
-use std::collections::HashSet;
-
-const MINT_WHITELIST: [&'static str; 4] = [
-    "HVbpJAQGNpkgBaYBZQBR1t7yFdvaYVp2vCQQfKKEN4tM",
-    "Crn4x1Y2HUKko7ox2EZMT6N2t2ZyH7eKtwkBGVnhEq1g",
-    "FrBfWJ4qE5sCzKm3k3JaAtqZcXUh4LvJygDeketsrsH4",
-    "2b1kV6DkPAnxd5ixfnxCpjxmKwqjjaYmCZfHsFu24GXo",
-];
 
+/// Transfer from a user token account signed by the user.
+///
+/// `hook_accounts` is only read when the mint has a transfer hook. It has to hold the
+/// `TransferHookApproval` of the current hook program of the mint, so a hook authority cannot point
+/// the mint of an existing pool at an unapproved program. The token program resolves the
+/// `ExtraAccountMetaList` validation account, the hook program and the extra accounts of the mint
+/// from it by key, so instructions pass all of their remaining accounts to every transfer leg:
+/// a transaction moving two hooked mints lists the hook accounts of both, in any order.
+// the arguments mirror the accounts and data of the transfer_checked instruction
+#[allow(clippy::too_many_arguments)]
 pub fn transfer_from_user_to_pool_vault<'a>(
     authority: AccountInfo<'a>,
     from: AccountInfo<'a>,
@@ -29,10 +45,26 @@ pub fn transfer_from_user_to_pool_vault<'a>(
     token_program: AccountInfo<'a>,
     amount: u64,
     mint_decimals: u8,
+    hook_accounts: &[AccountInfo<'a>],
 ) -> Result<()> {
     if amount == 0 {
         return Ok(());
     }
+    if let Some(hook_program_id) = get_transfer_hook_program_id(&mint)? {
+        check_transfer_hook_approval(&hook_program_id, hook_accounts)?;
+        return onchain::invoke_transfer_checked(
+            token_program.key,
+            from,
+            mint,
+            to_vault,
+            authority,
+            hook_accounts,
+            amount,
+            mint_decimals,
+            &[],
+        )
+        .map_err(Into::into);
+    }
     token_2022::transfer_checked(
         CpiContext::new(
             token_program.to_account_info(),
@@ -48,6 +80,9 @@ pub fn transfer_from_user_to_pool_vault<'a>(
     )
 }
 
+/// Transfer from a token account owned by the program authority, see
+/// `transfer_from_user_to_pool_vault` for the layout of `hook_accounts`
+#[allow(clippy::too_many_arguments)]
 pub fn transfer_from_pool_vault_to_user<'a>(
     authority: AccountInfo<'a>,
     from_vault: AccountInfo<'a>,
@@ -57,10 +92,26 @@ pub fn transfer_from_pool_vault_to_user<'a>(
     amount: u64,
     mint_decimals: u8,
     signer_seeds: &[&[&[u8]]],
+    hook_accounts: &[AccountInfo<'a>],
 ) -> Result<()> {
     if amount == 0 {
         return Ok(());
     }
+    if let Some(hook_program_id) = get_transfer_hook_program_id(&mint)? {
+        check_transfer_hook_approval(&hook_program_id, hook_accounts)?;
+        return onchain::invoke_transfer_checked(
+            token_program.key,
+            from_vault,
+            mint,
+            to,
+            authority,
+            hook_accounts,
+            amount,
+            mint_decimals,
+            signer_seeds,
+        )
+        .map_err(Into::into);
+    }
     token_2022::transfer_checked(
         CpiContext::new_with_signer(
             token_program.to_account_info(),
@@ -175,19 +226,176 @@ pub fn get_transfer_fee(mint_info: &AccountInfo, pre_fee_amount: u64) -> Result<
     Ok(fee)
 }
 
-pub fn is_supported_mint(mint_account: &InterfaceAccount<Mint>) -> Result<bool> {
+/// Same year length as the interest bearing extension, 365.24 days
+const SECONDS_PER_YEAR: i128 = 60 * 60 * 24 * 36524 / 100;
+const RATE_BPS_DENOMINATOR: i128 = 10000;
+
+/// Whether the ui amount of the mint is scaled from the raw amount.
+/// Only `InterestBearingConfig` is supported. `ScaledUiAmount` mints are out of scope: the extension
+/// needs spl-token-2022 8.0, which the anchor-spl 0.30 dependency does not allow, and such mints
+/// are rejected by `is_supported_mint` unless they are explicitly allowlisted.
+pub fn is_ui_amount_scaled_mint(mint_info: &AccountInfo) -> bool {
+    if *mint_info.owner == Token::id() {
+        return false;
+    }
+    let Ok(mint_data) = mint_info.try_borrow_data() else {
+        return false;
+    };
+    StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)
+        .map(|mint| mint.get_extension::<InterestBearingConfig>().is_ok())
+        .unwrap_or(false)
+}
+
+/// Get the ui amount multiplier of the mint at `unix_timestamp`, Q32.32.
+/// `ui_amount = amount * multiplier / 2^32`, unscaled mints return 1.0
+pub fn get_ui_amount_multiplier_x32(mint_info: &AccountInfo, unix_timestamp: i64) -> Result<u64> {
+    if *mint_info.owner == Token::id() {
+        return Ok(Q32 as u64);
+    }
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    let config = match mint.get_extension::<InterestBearingConfig>() {
+        Ok(config) => config,
+        Err(_) => return Ok(Q32 as u64),
+    };
+    // Same continuous compounding as the interest bearing extension, in fixed point:
+    // e^(pre_rate * pre_timespan / year) * e^(current_rate * post_timespan / year)
+    let initialization_timestamp = i64::from(config.initialization_timestamp);
+    let last_update_timestamp = i64::from(config.last_update_timestamp);
+    let pre_update_timespan = last_update_timestamp.saturating_sub(initialization_timestamp);
+    let post_update_timespan = unix_timestamp.saturating_sub(last_update_timestamp);
+    let rate_bps_seconds = i128::from(i16::from(config.pre_update_average_rate))
+        * i128::from(pre_update_timespan)
+        + i128::from(i16::from(config.current_rate)) * i128::from(post_update_timespan);
+    let multiplier_x32 = match rate_bps_seconds.checked_mul(1 << 64) {
+        Some(numerator) => exp_x32(numerator / (SECONDS_PER_YEAR * RATE_BPS_DENOMINATOR)),
+        None => None,
+    };
+    Ok(match multiplier_x32 {
+        Some(multiplier_x32) => multiplier_x32.max(1),
+        None if rate_bps_seconds < 0 => 1,
+        None => u64::MAX,
+    })
+}
+
+/// Convert the raw amount to ui amount space with the multiplier from `get_ui_amount_multiplier_x32`
+pub fn to_ui_amount(amount: u64, ui_amount_multiplier_x32: u64) -> u64 {
+    let ui_amount = u128::from(amount) * u128::from(ui_amount_multiplier_x32) / Q32;
+    u64::try_from(ui_amount).unwrap_or(u64::MAX)
+}
+
+/// Get the transfer hook program of the mint, if any
+pub fn get_transfer_hook_program_id(mint_info: &AccountInfo) -> Result<Option<Pubkey>> {
+    if *mint_info.owner == Token::id() {
+        return Ok(None);
+    }
+    let mint_data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+    Ok(transfer_hook::get_program_id(&mint))
+}
+
+/// Check the `TransferHookApproval` of the hook program is in `hook_accounts`
+fn check_transfer_hook_approval(
+    hook_program_id: &Pubkey,
+    hook_accounts: &[AccountInfo],
+) -> Result<()> {
+    require!(
+        contains_registry_account::<TransferHookApproval>(
+            TRANSFER_HOOK_APPROVAL_SEED,
+            hook_program_id,
+            hook_accounts,
+        ),
+        ErrorCode::TransferHookNotApproved
+    );
+    Ok(())
+}
+
+/// Check whether the registry account `T` derived from `seed` and `key` is in `registry_accounts`.
+fn contains_registry_account<T: AccountDeserialize>(
+    seed: &str,
+    key: &Pubkey,
+    registry_accounts: &[AccountInfo],
+) -> bool {
+    let (registry_key, _) =
+        Pubkey::find_program_address(&[seed.as_bytes(), key.as_ref()], &crate::id());
+    registry_accounts.iter().any(|account_info| {
+        *account_info.key == registry_key
+            && *account_info.owner == crate::id()
+            && account_info
+                .try_borrow_data()
+                .map(|data| T::try_deserialize(&mut data.as_ref()).is_ok())
+                .unwrap_or(false)
+    })
+}
+
+/// Check the mint against the risky mint policy of the config, returns an error if it is rejected
+fn check_mint_policy(mint_account: &InterfaceAccount<Mint>, amm_config: &AmmConfig) -> Result<()> {
+    if !amm_config.get_mint_policy_by_bit(MintPolicyBitIndex::FreezeAuthority) {
+        require!(
+            mint_account.freeze_authority.is_none(),
+            ErrorCode::MintFreezeAuthorityNotAllowed
+        );
+    }
+    if !amm_config.get_mint_policy_by_bit(MintPolicyBitIndex::MintAuthority) {
+        require!(
+            mint_account.mint_authority.is_none(),
+            ErrorCode::MintAuthorityNotAllowed
+        );
+    }
+    let mint_info = mint_account.to_account_info();
+    if !amm_config.get_mint_policy_by_bit(MintPolicyBitIndex::PermanentDelegate)
+        && *mint_info.owner != Token::id()
+    {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        if let Ok(permanent_delegate) = mint.get_extension::<PermanentDelegate>() {
+            require!(
+                Option::<Pubkey>::from(permanent_delegate.delegate).is_none(),
+                ErrorCode::MintPermanentDelegateNotAllowed
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Check whether the mint can be used in a pool of the config.
+/// `registry_accounts` holds the `MintAllowlistEntry` of allowed mints and
+/// the `TransferHookApproval` of approved transfer hook programs.
+/// Transfers check the approval of the current hook program again, revoking one also stops
+/// the transfers of the hook mints in existing pools.
+pub fn is_supported_mint(
+    mint_account: &InterfaceAccount<Mint>,
+    amm_config: &AmmConfig,
+    registry_accounts: &[AccountInfo],
+) -> Result<bool> {
+    check_mint_policy(mint_account, amm_config)?;
     let mint_info = mint_account.to_account_info();
     if *mint_info.owner == Token::id() {
         return Ok(true);
     }
-    let mint_whitelist: HashSet<&str> = MINT_WHITELIST.into_iter().collect();
-    if mint_whitelist.contains(mint_account.key().to_string().as_str()) {
+    if contains_registry_account::<MintAllowlistEntry>(
+        MINT_ALLOWLIST_SEED,
+        &mint_account.key(),
+        registry_accounts,
+    ) {
         return Ok(true);
     }
     let mint_data = mint_info.try_borrow_data()?;
     let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
     let extensions = mint.get_extension_types()?;
     for e in extensions {
+        if e == ExtensionType::TransferHook {
+            if let Some(program_id) = transfer_hook::get_program_id(&mint) {
+                if !contains_registry_account::<TransferHookApproval>(
+                    TRANSFER_HOOK_APPROVAL_SEED,
+                    &program_id,
+                    registry_accounts,
+                ) {
+                    return Ok(false);
+                }
+            }
+            continue;
+        }
         if e != ExtensionType::TransferFeeConfig
             && e != ExtensionType::MetadataPointer
             && e != ExtensionType::TokenMetadata
@@ -242,6 +450,110 @@ pub fn create_token_account<'a>(
     ))
 }
 
+/// Create the pool lp mint.
+/// When `metadata` is given the mint is created under token program 2022 with
+/// `MetadataPointer` and `TokenMetadata` pointing at itself, otherwise a plain spl token mint is created.
+// the system and token program instructions issued here each take their own accounts and signer seeds
+#[allow(clippy::too_many_arguments)]
+pub fn create_lp_mint<'a>(
+    authority: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    lp_mint: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    decimals: u8,
+    metadata: Option<(String, String, String)>,
+    mint_signer_seeds: &[&[u8]],
+    authority_signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let space = if metadata.is_some() {
+        ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&[
+            ExtensionType::MetadataPointer,
+        ])?
+    } else {
+        spl_token_2022::state::Mint::LEN
+    };
+    create_or_allocate_account(
+        token_program.key,
+        payer.to_account_info(),
+        system_program.to_account_info(),
+        lp_mint.to_account_info(),
+        mint_signer_seeds,
+        space,
+    )?;
+
+    if let Some((name, symbol, uri)) = &metadata {
+        // token metadata is reallocated into the mint by token program 2022,
+        // so the rent for it must be funded up front.
+        let token_metadata = TokenMetadata {
+            update_authority: OptionalNonZeroPubkey::try_from(Some(authority.key()))?,
+            mint: lp_mint.key(),
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            additional_metadata: vec![],
+        };
+        let required_lamports = Rent::get()?
+            .minimum_balance(space + token_metadata.tlv_size_of()?)
+            .saturating_sub(lp_mint.lamports());
+        if required_lamports > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: payer.to_account_info(),
+                        to: lp_mint.to_account_info(),
+                    },
+                ),
+                required_lamports,
+            )?;
+        }
+        metadata_pointer_initialize(
+            CpiContext::new(
+                token_program.to_account_info(),
+                MetadataPointerInitialize {
+                    token_program_id: token_program.to_account_info(),
+                    mint: lp_mint.to_account_info(),
+                },
+            ),
+            Some(authority.key()),
+            Some(lp_mint.key()),
+        )?;
+    }
+
+    initialize_mint2(
+        CpiContext::new(
+            token_program.to_account_info(),
+            InitializeMint2 {
+                mint: lp_mint.to_account_info(),
+            },
+        ),
+        decimals,
+        authority.key,
+        None,
+    )?;
+
+    if let Some((name, symbol, uri)) = metadata {
+        token_metadata_initialize(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                TokenMetadataInitialize {
+                    token_program_id: token_program.to_account_info(),
+                    metadata: lp_mint.to_account_info(),
+                    update_authority: authority.to_account_info(),
+                    mint_authority: authority.to_account_info(),
+                    mint: lp_mint.to_account_info(),
+                },
+                authority_signer_seeds,
+            ),
+            name,
+            symbol,
+            uri,
+        )?;
+    }
+    Ok(())
+}
+
 pub fn create_or_allocate_account<'a>(
     program_id: &Pubkey,
     payer: AccountInfo<'a>,
@@ -296,3 +608,355 @@ pub fn create_or_allocate_account<'a>(
     }
     Ok(())
 }
+
+#[cfg(test)]
+pub mod token_test {
+    use super::*;
+    use crate::states::MINT_POLICY_MASK;
+    use anchor_lang::solana_program::program_option::COption;
+    use anchor_spl::token_interface::spl_token_2022::{
+        extension::{
+            transfer_hook::TransferHook, BaseStateWithExtensionsMut, StateWithExtensionsMut,
+        },
+        state::Mint as MintState,
+    };
+    use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+    use spl_transfer_hook_interface::{
+        get_extra_account_metas_address, instruction::ExecuteInstruction,
+    };
+
+    pub struct TestAccount {
+        pub key: Pubkey,
+        pub owner: Pubkey,
+        pub lamports: u64,
+        pub data: Vec<u8>,
+    }
+
+    impl TestAccount {
+        pub fn new(key: Pubkey, owner: Pubkey, data: Vec<u8>) -> Self {
+            Self {
+                key,
+                owner,
+                lamports: 1_000_000,
+                data,
+            }
+        }
+
+        pub fn info(&mut self) -> AccountInfo<'_> {
+            AccountInfo::new(
+                &self.key,
+                false,
+                true,
+                &mut self.lamports,
+                &mut self.data,
+                &self.owner,
+                false,
+                0,
+            )
+        }
+    }
+
+    pub fn token_2022_mint(hook_program_id: Option<Pubkey>) -> TestAccount {
+        let extensions: &[ExtensionType] = if hook_program_id.is_some() {
+            &[ExtensionType::TransferHook]
+        } else {
+            &[]
+        };
+        let space = ExtensionType::try_calculate_account_len::<MintState>(extensions).unwrap();
+        let mut data = vec![0u8; space];
+        let mut mint =
+            StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        if let Some(program_id) = hook_program_id {
+            let transfer_hook = mint.init_extension::<TransferHook>(true).unwrap();
+            transfer_hook.program_id = OptionalNonZeroPubkey::try_from(Some(program_id)).unwrap();
+        }
+        mint.base = MintState {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        TestAccount::new(Pubkey::new_unique(), token_2022::ID, data)
+    }
+
+    fn policy_mint(
+        freeze_authority: bool,
+        mint_authority: bool,
+        permanent_delegate: bool,
+    ) -> TestAccount {
+        let extensions: &[ExtensionType] = if permanent_delegate {
+            &[ExtensionType::PermanentDelegate]
+        } else {
+            &[]
+        };
+        let space = ExtensionType::try_calculate_account_len::<MintState>(extensions).unwrap();
+        let mut data = vec![0u8; space];
+        let mut mint =
+            StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        if permanent_delegate {
+            let delegate = mint.init_extension::<PermanentDelegate>(true).unwrap();
+            delegate.delegate =
+                OptionalNonZeroPubkey::try_from(Some(Pubkey::new_unique())).unwrap();
+        }
+        let authority = |enabled: bool| {
+            if enabled {
+                COption::Some(Pubkey::new_unique())
+            } else {
+                COption::None
+            }
+        };
+        mint.base = MintState {
+            mint_authority: authority(mint_authority),
+            freeze_authority: authority(freeze_authority),
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        TestAccount::new(Pubkey::new_unique(), token_2022::ID, data)
+    }
+
+    fn interest_bearing_mint(config: InterestBearingConfig) -> TestAccount {
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::InterestBearingConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; space];
+        let mut mint =
+            StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+        *mint.init_extension::<InterestBearingConfig>(true).unwrap() = config;
+        mint.base = MintState {
+            is_initialized: true,
+            ..Default::default()
+        };
+        mint.pack_base();
+        mint.init_account_type().unwrap();
+        TestAccount::new(Pubkey::new_unique(), token_2022::ID, data)
+    }
+
+    fn transfer_hook_approval(program_id: Pubkey) -> TestAccount {
+        let (key, _) = Pubkey::find_program_address(
+            &[TRANSFER_HOOK_APPROVAL_SEED.as_bytes(), program_id.as_ref()],
+            &crate::id(),
+        );
+        let mut data = Vec::new();
+        TransferHookApproval {
+            program_id,
+            ..Default::default()
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        TestAccount::new(key, crate::id(), data)
+    }
+
+    fn extra_account_meta_list(mint: &Pubkey, program_id: &Pubkey) -> TestAccount {
+        let mut data = vec![0u8; ExtraAccountMetaList::size_of(0).unwrap()];
+        ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &[]).unwrap();
+        TestAccount::new(
+            get_extra_account_metas_address(mint, program_id),
+            *program_id,
+            data,
+        )
+    }
+
+    #[test]
+    fn transfer_hook_program_id_test() {
+        let hook_program_id = Pubkey::new_unique();
+        let mut hook_mint = token_2022_mint(Some(hook_program_id));
+        assert_eq!(
+            get_transfer_hook_program_id(&hook_mint.info()).unwrap(),
+            Some(hook_program_id)
+        );
+        let mut mint = token_2022_mint(None);
+        assert_eq!(get_transfer_hook_program_id(&mint.info()).unwrap(), None);
+    }
+
+    #[test]
+    fn ui_amount_multiplier_test() {
+        let initialization_timestamp = 1_700_000_000i64;
+        let last_update_timestamp = initialization_timestamp + 200 * 24 * 3600;
+        for (pre_update_average_rate, current_rate) in [(500i16, 800i16), (-300, 1200), (0, 0)] {
+            let config = InterestBearingConfig {
+                initialization_timestamp: initialization_timestamp.into(),
+                pre_update_average_rate: pre_update_average_rate.into(),
+                last_update_timestamp: last_update_timestamp.into(),
+                current_rate: current_rate.into(),
+                ..Default::default()
+            };
+            let mut mint = interest_bearing_mint(config);
+            for unix_timestamp in [
+                last_update_timestamp,
+                last_update_timestamp + 3600,
+                last_update_timestamp + 3 * 365 * 24 * 3600,
+            ] {
+                let amount = 1_000_000_000u64;
+                let expected: f64 = config
+                    .amount_to_ui_amount(amount, 0, unix_timestamp)
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                let multiplier_x32 =
+                    get_ui_amount_multiplier_x32(&mint.info(), unix_timestamp).unwrap();
+                let actual = to_ui_amount(amount, multiplier_x32) as f64;
+                assert!((actual - expected).abs() <= 1.0, "{} {}", actual, expected);
+            }
+        }
+
+        let mut mint = token_2022_mint(None);
+        assert_eq!(
+            get_ui_amount_multiplier_x32(&mint.info(), initialization_timestamp).unwrap(),
+            Q32 as u64
+        );
+    }
+
+    #[test]
+    fn mint_policy_test() {
+        let mut clean_mint = policy_mint(false, false, false);
+        let cases = [
+            (
+                MintPolicyBitIndex::FreezeAuthority,
+                policy_mint(true, false, false),
+                ErrorCode::MintFreezeAuthorityNotAllowed,
+            ),
+            (
+                MintPolicyBitIndex::PermanentDelegate,
+                policy_mint(false, false, true),
+                ErrorCode::MintPermanentDelegateNotAllowed,
+            ),
+            (
+                MintPolicyBitIndex::MintAuthority,
+                policy_mint(false, true, false),
+                ErrorCode::MintAuthorityNotAllowed,
+            ),
+        ];
+        for (bit, mut mint, error) in cases {
+            let policy = 1u64 << (bit as u8);
+            let mint_info = mint.info();
+            let mint_account = InterfaceAccount::<Mint>::try_from(&mint_info).unwrap();
+            let clean_mint_info = clean_mint.info();
+            let clean_mint_account = InterfaceAccount::<Mint>::try_from(&clean_mint_info).unwrap();
+
+            // allowed by default
+            let mut amm_config = AmmConfig::default();
+            assert!(check_mint_policy(&mint_account, &amm_config).is_ok());
+
+            amm_config.mint_policy = policy;
+            assert_eq!(
+                check_mint_policy(&mint_account, &amm_config).unwrap_err(),
+                error.into()
+            );
+            assert!(check_mint_policy(&clean_mint_account, &amm_config).is_ok());
+
+            // the other bits do not reject the mint
+            amm_config.mint_policy = MINT_POLICY_MASK & !policy;
+            assert!(check_mint_policy(&mint_account, &amm_config).is_ok());
+        }
+    }
+
+    #[test]
+    fn transfer_hook_approval_test() {
+        let amm_config = AmmConfig::default();
+        let hook_program_id = Pubkey::new_unique();
+        let mut hook_mint = token_2022_mint(Some(hook_program_id));
+        let hook_mint_info = hook_mint.info();
+        let hook_mint_account = InterfaceAccount::<Mint>::try_from(&hook_mint_info).unwrap();
+
+        // not approved
+        assert!(!is_supported_mint(&hook_mint_account, &amm_config, &[]).unwrap());
+        let mut other_approval = transfer_hook_approval(Pubkey::new_unique());
+        assert!(
+            !is_supported_mint(&hook_mint_account, &amm_config, &[other_approval.info()]).unwrap()
+        );
+
+        // approved
+        let mut approval = transfer_hook_approval(hook_program_id);
+        assert!(is_supported_mint(&hook_mint_account, &amm_config, &[approval.info()]).unwrap());
+
+        // an account at the approval address not owned by the program
+        let mut fake_approval = transfer_hook_approval(hook_program_id);
+        fake_approval.owner = Pubkey::new_unique();
+        assert!(
+            !is_supported_mint(&hook_mint_account, &amm_config, &[fake_approval.info()]).unwrap()
+        );
+
+        // revoked, the closed approval is handed back to the system program
+        approval.owner = system_program::ID;
+        approval.lamports = 0;
+        approval.data.clear();
+        assert!(!is_supported_mint(&hook_mint_account, &amm_config, &[approval.info()]).unwrap());
+        // the mint of an existing pool still resolves its hook for transfers
+        assert_eq!(
+            get_transfer_hook_program_id(&hook_mint_info).unwrap(),
+            Some(hook_program_id)
+        );
+    }
+
+    #[test]
+    fn transfer_hook_accounts_test() {
+        // both legs of a swap get the same hook accounts, each mint picks its own
+        let (program_0, program_1) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut mint_0 = token_2022_mint(Some(program_0));
+        let mut mint_1 = token_2022_mint(Some(program_1));
+        let mut validation_0 = extra_account_meta_list(&mint_0.key, &program_0);
+        let mut validation_1 = extra_account_meta_list(&mint_1.key, &program_1);
+        let mut program_account_0 = TestAccount::new(program_0, Pubkey::default(), vec![]);
+        let mut program_account_1 = TestAccount::new(program_1, Pubkey::default(), vec![]);
+        let mut approval_0 = transfer_hook_approval(program_0);
+        let mut approval_1 = transfer_hook_approval(program_1);
+        // the hook program of mint_0 changed to an unapproved program
+        let mut repointed_mint = token_2022_mint(Some(Pubkey::new_unique()));
+        repointed_mint.key = mint_0.key;
+        let mut authority = TestAccount::new(Pubkey::new_unique(), Pubkey::default(), vec![]);
+        let mut source = TestAccount::new(Pubkey::new_unique(), token_2022::ID, vec![]);
+        let mut destination = TestAccount::new(Pubkey::new_unique(), token_2022::ID, vec![]);
+        let mut token_program = TestAccount::new(token_2022::ID, Pubkey::default(), vec![]);
+        let transfer_accounts = [
+            authority.info(),
+            source.info(),
+            destination.info(),
+            token_program.info(),
+        ];
+        let (mint_0_info, mint_1_info) = (mint_0.info(), mint_1.info());
+        let hook_accounts = [
+            validation_1.info(),
+            approval_0.info(),
+            program_account_0.info(),
+            validation_0.info(),
+            approval_1.info(),
+            program_account_1.info(),
+        ];
+        fn transfer<'a>(
+            [authority, source, destination, token_program]: &[AccountInfo<'a>; 4],
+            mint_info: &AccountInfo<'a>,
+            hook_accounts: &[AccountInfo<'a>],
+        ) -> Result<()> {
+            transfer_from_user_to_pool_vault(
+                authority.clone(),
+                source.clone(),
+                destination.clone(),
+                mint_info.clone(),
+                token_program.clone(),
+                1,
+                6,
+                hook_accounts,
+            )
+        }
+        assert!(transfer(&transfer_accounts, &mint_0_info, &hook_accounts).is_ok());
+        assert!(transfer(&transfer_accounts, &mint_1_info, &hook_accounts).is_ok());
+
+        // the approval of the current hook program is required
+        let without_approval_0 = [&hook_accounts[..1], &hook_accounts[2..]].concat();
+        assert_eq!(
+            transfer(&transfer_accounts, &mint_0_info, &without_approval_0).unwrap_err(),
+            ErrorCode::TransferHookNotApproved.into()
+        );
+        assert_eq!(
+            transfer(&transfer_accounts, &repointed_mint.info(), &hook_accounts).unwrap_err(),
+            ErrorCode::TransferHookNotApproved.into()
+        );
+
+        // a missing validation account fails the transfer
+        assert!(transfer(&transfer_accounts, &mint_0_info, &hook_accounts[..3]).is_err());
+    }
+}
@@ -411,7 +411,7 @@ describe("K Token Swap Fee Discount", () => {
 
     // Use normal swap_base_input (without K token discount)
     await program.methods
-      .swapBaseInput(swapAmount, minAmountOut)
+      .swapBaseInput(swapAmount, minAmountOut, new BN(0), new BN(0))
       .accounts({
         payer: owner.publicKey,
         ammConfig,
@@ -1,7 +1,13 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { KedolikCpSwap } from "../target/types/kedolik_cp_swap";
-import { setupSwapTest, swap_base_input, swap_base_output } from "./utils";
+import {
+  setupSwapTest,
+  swap_base_input,
+  swap_base_output,
+  quote_swap_base_input,
+  getSwapReturnData,
+} from "./utils";
 import { assert } from "chai";
 import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
 
@@ -193,6 +199,49 @@ describe("swap test", () => {
       BigInt(amount_out.toString())
     );
   });
+
+  it("quote swap base input returns the same data as the swap", async () => {
+    const { configAddress, poolState } = await setupSwapTest(
+      program,
+      anchor.getProvider().connection,
+      owner,
+      {
+        config_index: 0,
+        tradeFeeRate: new BN(10),
+        protocolFeeRate: new BN(1000),
+        fundFeeRate: new BN(25000),
+        create_fee: new BN(0),
+      },
+      { transferFeeBasisPoints: 0, MaxFee: 0 }
+    );
+    await sleep(1000);
+    let amount_in = new BN(100000000);
+    const quoteData = await quote_swap_base_input(
+      program,
+      owner,
+      configAddress,
+      poolState.token0Mint,
+      poolState.token1Mint,
+      amount_in
+    );
+    const signature = await swap_base_input(
+      program,
+      owner,
+      configAddress,
+      poolState.token0Mint,
+      poolState.token0Program,
+      poolState.token1Mint,
+      poolState.token1Program,
+      amount_in,
+      new BN(0),
+      confirmOptions
+    );
+    const swapData = await getSwapReturnData(
+      anchor.getProvider().connection,
+      signature
+    );
+    assert.equal(quoteData.toString("hex"), swapData.toString("hex"));
+  });
 });
 
 function sleep(ms: number): Promise<void> {
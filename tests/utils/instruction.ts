@@ -20,11 +20,13 @@ import {
   sendTransaction,
   getAmmConfigAddress,
   getAuthAddress,
+  getProtocolStateAddress,
   getPoolAddress,
   getPoolLpMintAddress,
   getPoolVaultAddress,
   createTokenMintAndAssociatedTokenAccount,
   getOrcleAccountAddress,
+  getMintAllowlistAddress,
 } from "./index";
 
 import { ASSOCIATED_PROGRAM_ID } from "@coral-xyz/anchor/dist/cjs/utils/token";
@@ -222,6 +224,61 @@ export async function setupSwapTest(
   return { configAddress, poolAddress, poolState };
 }
 
+export async function initializeProtocolState(
+  program: Program<KedolikCpSwap>,
+  connection: Connection,
+  owner: Signer,
+  confirmOptions?: ConfirmOptions
+): Promise<PublicKey> {
+  const [address, _] = await getProtocolStateAddress(program.programId);
+  if (await accountExist(connection, address)) {
+    return address;
+  }
+
+  const ix = await program.methods
+    .initializeProtocolState()
+    .accounts({
+      owner: owner.publicKey,
+      protocolState: address,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+
+  const tx = await sendTransaction(connection, [ix], [owner], confirmOptions);
+  console.log("init protocol state tx: ", tx);
+  return address;
+}
+
+export async function addAllowedMint(
+  program: Program<KedolikCpSwap>,
+  connection: Connection,
+  owner: Signer,
+  mint: PublicKey,
+  confirmOptions?: ConfirmOptions
+): Promise<PublicKey> {
+  const [address, _] = await getMintAllowlistAddress(mint, program.programId);
+  if (await accountExist(connection, address)) {
+    return address;
+  }
+  const [protocolState] = await getProtocolStateAddress(program.programId);
+
+  const ix = await program.methods
+    .addAllowedMint()
+    .accounts({
+      owner: owner.publicKey,
+      protocolState,
+      mint,
+      mintAllowlistEntry: address,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    })
+    .instruction();
+
+  const tx = await sendTransaction(connection, [ix], [owner], confirmOptions);
+  console.log("add allowed mint tx: ", tx);
+  return address;
+}
+
 export async function createAmmConfig(
   program: Program<KedolikCpSwap>,
   connection: Connection,
@@ -242,6 +299,12 @@ export async function createAmmConfig(
   if (await accountExist(connection, address)) {
     return address;
   }
+  const protocolState = await initializeProtocolState(
+    program,
+    connection,
+    owner,
+    confirmOptions
+  );
 
   const ix = await program.methods
     .createAmmConfig(
@@ -255,6 +318,7 @@ export async function createAmmConfig(
     )
     .accounts({
       owner: owner.publicKey,
+      protocolState,
       ammConfig: address,
       systemProgram: SystemProgram.programId,
     })
@@ -328,7 +392,12 @@ export async function initialize(
     token1Program
   );
   await program.methods
-    .initialize(initAmount.initAmount0, initAmount.initAmount1, new BN(0))
+    .initialize(
+      initAmount.initAmount0,
+      initAmount.initAmount1,
+      new BN(0),
+      null
+    )
     .accounts({
       creator: creator.publicKey,
       ammConfig: configAddress,
@@ -345,6 +414,7 @@ export async function initialize(
       createPoolFee,
       observationState: observationAddress,
       tokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram2022: TOKEN_2022_PROGRAM_ID,
       token0Program: token0Program,
       token1Program: token1Program,
       systemProgram: SystemProgram.programId,
@@ -412,6 +482,8 @@ export async function deposit(
     token1Program
   );
 
+  const [protocolState] = await getProtocolStateAddress(program.programId);
+
   const tx = await program.methods
     .deposit(lp_token_amount, maximum_token_0_amount, maximum_token_1_amount)
     .accounts({
@@ -428,6 +500,7 @@ export async function deposit(
       vault0Mint: token0,
       vault1Mint: token1,
       lpMint: lpMintAddress,
+      protocolState,
     })
     .rpc(confirmOptions);
   return tx;
@@ -490,6 +563,8 @@ export async function withdraw(
     token1Program
   );
 
+  const [protocolState] = await getProtocolStateAddress(program.programId);
+
   const tx = await program.methods
     .withdraw(lp_token_amount, minimum_token_0_amount, minimum_token_1_amount)
     .accounts({
@@ -507,6 +582,7 @@ export async function withdraw(
       vault1Mint: token1,
       lpMint: lpMintAddress,
       memoProgram: new PublicKey("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"),
+      protocolState,
     })
     .rpc(confirmOptions)
     .catch();
@@ -562,8 +638,10 @@ export async function swap_base_input(
     program.programId
   );
 
+  const [protocolState] = await getProtocolStateAddress(program.programId);
+
   const tx = await program.methods
-    .swapBaseInput(amount_in, minimum_amount_out)
+    .swapBaseInput(amount_in, minimum_amount_out, new BN(0), new BN(0))
     .accounts({
       payer: owner.publicKey,
       authority: auth,
@@ -578,6 +656,9 @@ export async function swap_base_input(
       inputTokenMint: inputToken,
       outputTokenMint: outputToken,
       observationState: observationAddress,
+      protocolState,
+      userStake: null,
+      referrerTokenAccount: null,
     })
     .rpc(confirmOptions);
 
@@ -635,8 +716,10 @@ export async function swap_base_output(
     program.programId
   );
 
+  const [protocolState] = await getProtocolStateAddress(program.programId);
+
   const tx = await program.methods
-    .swapBaseOutput(max_amount_in, amount_out_less_fee)
+    .swapBaseOutput(max_amount_in, amount_out_less_fee, new BN(0), new BN(0))
     .accounts({
       payer: owner.publicKey,
       authority: auth,
@@ -651,8 +734,80 @@ export async function swap_base_output(
       inputTokenMint: inputToken,
       outputTokenMint: outputToken,
       observationState: observationAddress,
+      protocolState,
+      userStake: null,
+      referrerTokenAccount: null,
     })
     .rpc(confirmOptions);
 
   return tx;
 }
+
+export async function quote_swap_base_input(
+  program: Program<KedolikCpSwap>,
+  owner: Signer,
+  configAddress: PublicKey,
+  inputToken: PublicKey,
+  outputToken: PublicKey,
+  amount_in: BN
+) {
+  const [poolAddress] = await getPoolAddress(
+    configAddress,
+    inputToken,
+    outputToken,
+    program.programId
+  );
+  const [inputVault] = await getPoolVaultAddress(
+    poolAddress,
+    inputToken,
+    program.programId
+  );
+  const [outputVault] = await getPoolVaultAddress(
+    poolAddress,
+    outputToken,
+    program.programId
+  );
+  const [observationAddress] = await getOrcleAccountAddress(
+    poolAddress,
+    program.programId
+  );
+  const [protocolState] = await getProtocolStateAddress(program.programId);
+
+  const tx = await program.methods
+    .quoteSwapBaseInput(amount_in, new BN(0))
+    .accounts({
+      ammConfig: configAddress,
+      poolState: poolAddress,
+      inputVault,
+      outputVault,
+      inputTokenMint: inputToken,
+      outputTokenMint: outputToken,
+      observationState: observationAddress,
+      userStake: null,
+      protocolState,
+      referrerTokenAccount: null,
+    })
+    .transaction();
+  tx.feePayer = owner.publicKey;
+  const simulation = await program.provider.connection.simulateTransaction(
+    tx,
+    [owner]
+  );
+  if (simulation.value.err) {
+    throw new Error(JSON.stringify(simulation.value.err));
+  }
+  return Buffer.from(simulation.value.returnData.data[0], "base64");
+}
+
+// the SwapReturnData set by a confirmed swap transaction
+export async function getSwapReturnData(
+  connection: Connection,
+  signature: string
+) {
+  await connection.confirmTransaction(signature, "confirmed");
+  const tx = await connection.getTransaction(signature, {
+    commitment: "confirmed",
+    maxSupportedTransactionVersion: 0,
+  });
+  return Buffer.from(tx.meta.returnData.data[0], "base64");
+}
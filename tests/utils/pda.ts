@@ -25,6 +25,14 @@ export const ORACLE_SEED = Buffer.from(
   anchor.utils.bytes.utf8.encode("observation")
 );
 
+export const PROTOCOL_STATE_SEED = Buffer.from(
+  anchor.utils.bytes.utf8.encode("protocol_state")
+);
+
+export const MINT_ALLOWLIST_SEED = Buffer.from(
+  anchor.utils.bytes.utf8.encode("mint_allowlist")
+);
+
 export function u16ToBytes(num: number) {
   const arr = new ArrayBuffer(2);
   const view = new DataView(arr);
@@ -64,6 +72,27 @@ export async function getAmmConfigAddress(
   return [address, bump];
 }
 
+export async function getProtocolStateAddress(
+  programId: PublicKey
+): Promise<[PublicKey, number]> {
+  const [address, bump] = await PublicKey.findProgramAddress(
+    [PROTOCOL_STATE_SEED],
+    programId
+  );
+  return [address, bump];
+}
+
+export async function getMintAllowlistAddress(
+  mint: PublicKey,
+  programId: PublicKey
+): Promise<[PublicKey, number]> {
+  const [address, bump] = await PublicKey.findProgramAddress(
+    [MINT_ALLOWLIST_SEED, mint.toBuffer()],
+    programId
+  );
+  return [address, bump];
+}
+
 export async function getAuthAddress(
   programId: PublicKey
 ): Promise<[PublicKey, number]> {